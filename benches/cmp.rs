@@ -0,0 +1,46 @@
+use std::cmp::Ordering;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use wt_version::Version;
+
+/// Fixed seed xorshift, so every run compares the same pairs
+fn random_pairs(n: usize, map: impl Fn(u64) -> Version) -> Vec<(Version, Version)> {
+	let mut state = 0x2_0021_0005_000E_u64;
+	let mut next = move || {
+		state ^= state << 13;
+		state ^= state >> 7;
+		state ^= state << 17;
+		map(state)
+	};
+	(0..n).map(|_| (next(), next())).collect()
+}
+
+/// Realistic versions share global and mostly major, so they rarely exit early
+fn realistic(value: u64) -> Version {
+	let [_, major, minor, patch] = Version::from_u64(value).components();
+	Version::new(2, 30 + major % 4, minor % 8, patch % 32)
+}
+
+fn field_by_field(a: &Version, b: &Version) -> Ordering {
+	a.global()
+		.cmp(&b.global())
+		.then_with(|| a.major().cmp(&b.major()))
+		.then_with(|| a.minor().cmp(&b.minor()))
+		.then_with(|| a.patch().cmp(&b.patch()))
+}
+
+fn cmp(c: &mut Criterion) {
+	for (name, pairs) in [("uniform", random_pairs(10_000, Version::from_u64)), ("realistic", random_pairs(10_000, realistic))] {
+		let mut group = c.benchmark_group(format!("cmp_{name}"));
+		group.bench_function("to_u64", |bench| {
+			bench.iter(|| pairs.iter().map(|(a, b)| black_box(a).cmp(black_box(b)) as i64).sum::<i64>())
+		});
+		group.bench_function("field_by_field", |bench| {
+			bench.iter(|| pairs.iter().map(|(a, b)| field_by_field(black_box(a), black_box(b)) as i64).sum::<i64>())
+		});
+		group.finish();
+	}
+}
+
+criterion_group!(benches, cmp);
+criterion_main!(benches);
@@ -0,0 +1,28 @@
+use arbitrary::{Arbitrary, Unstructured};
+
+use crate::Version;
+
+impl<'a> Arbitrary<'a> for Version {
+	fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+		Ok(Self::new(u.arbitrary()?, u.arbitrary()?, u.arbitrary()?, u.arbitrary()?))
+	}
+
+	fn size_hint(depth: usize) -> (usize, Option<usize>) {
+		<[u16; 4]>::size_hint(depth)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use arbitrary::{Arbitrary, Unstructured};
+
+	use crate::Version;
+
+	#[test]
+	fn deterministic() {
+		let bytes = [2, 0, 33, 0, 5, 0, 14, 0];
+		let v = Version::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+		assert_eq!(v, Version::arbitrary(&mut Unstructured::new(&bytes)).unwrap());
+		assert_eq!(v, Version::new(2, 33, 5, 14));
+	}
+}
@@ -0,0 +1,67 @@
+use crate::Version;
+
+/// Builds a [`Version`] from only the components that matter, see [`Version::builder`]
+///
+/// Unspecified components default to global 2 and zero for everything else
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct VersionBuilder {
+	version: Version,
+}
+
+impl VersionBuilder {
+	pub const fn new() -> Self {
+		Self {
+			version: Version::new(2, 0, 0, 0),
+		}
+	}
+
+	pub const fn global(mut self, global: u16) -> Self {
+		self.version.global = global;
+		self
+	}
+
+	pub const fn major(mut self, major: u16) -> Self {
+		self.version.major = major;
+		self
+	}
+
+	pub const fn minor(mut self, minor: u16) -> Self {
+		self.version.minor = minor;
+		self
+	}
+
+	pub const fn patch(mut self, patch: u16) -> Self {
+		self.version.patch = patch;
+		self
+	}
+
+	pub const fn build(self) -> Version {
+		self.version
+	}
+}
+
+impl Default for VersionBuilder {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::Version;
+
+	#[test]
+	fn partial() {
+		assert_eq!(Version::builder().major(33).minor(5).build(), Version::new(2, 33, 5, 0))
+	}
+
+	#[test]
+	fn defaults() {
+		assert_eq!(Version::builder().build(), Version::new(2, 0, 0, 0))
+	}
+
+	#[test]
+	fn all_components() {
+		assert_eq!(Version::builder().global(1).major(33).minor(5).patch(14).build(), Version::new(1, 33, 5, 14))
+	}
+}
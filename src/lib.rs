@@ -1,9 +1,48 @@
-use std::cmp::Ordering;
-use std::fmt::{Display, Formatter};
-use std::str::FromStr;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{self, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::num::ParseIntError;
+use core::ops::{Add, Index, Range, RangeInclusive, Sub};
+use core::str::FromStr;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+mod builder;
+mod macros;
+#[cfg(feature = "proptest")]
+mod proptest_impl;
+mod range;
+pub mod req;
+#[cfg(feature = "schemars")]
+mod schemars_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod tagged;
+
+pub use builder::VersionBuilder;
+#[doc(hidden)]
+pub use macros::__parse_literal;
+pub use range::VersionRange;
+pub use tagged::TaggedVersion;
 
 /// A version in the form of `2.33.5.14`
-#[derive(Debug, Copy, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, Hash)]
+///
+/// With the `serde` feature, serializes as the dotted string form, or as the packed [`Version::to_u64`] for binary formats.
+/// Human readable formats still accept the legacy struct of four components, binary formats do not: data written as the
+/// old derived struct, such as four `u16`s in bincode, silently decodes to a wrong version and has to be migrated by hand
+///
+/// With the `rkyv` feature, archives as the four components in declaration order as little endian `u16`s.
+/// This layout is part of the archive format, reordering or resizing the fields invalidates existing archives
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct Version {
 	/// Pretty much always just 2
 	global: u16,
@@ -12,7 +51,55 @@ pub struct Version {
 	patch: u16,
 }
 
+/// A single component of a [`Version`], from most to least significant
+///
+/// Ordered by significance, so `Global` is the smallest
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum VersionLevel {
+	Global,
+	Major,
+	Minor,
+	Patch,
+}
+
+impl VersionLevel {
+	/// Every level from most to least significant
+	pub fn iter() -> impl Iterator<Item = VersionLevel> {
+		[VersionLevel::Global, VersionLevel::Major, VersionLevel::Minor, VersionLevel::Patch].into_iter()
+	}
+}
+
+/// How closely two versions must match to be compatible, see [`Version::is_compatible_with`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum CompatibilityPolicy {
+	/// Every component must match
+	Exact,
+	/// Global, major and minor must match
+	SameMinor,
+	/// Global and major must match
+	SameMajor,
+	/// Only global must match
+	SameGlobal,
+}
+
 impl Version {
+	/// The lowest representable version, `0.0.0.0`
+	pub const MIN: Version = Version::new(0, 0, 0, 0);
+
+	/// The highest representable version, `65535.65535.65535.65535`
+	pub const MAX: Version = Version::new(u16::MAX, u16::MAX, u16::MAX, u16::MAX);
+
+	/// Bit offset of global in [`Version::to_u64`]
+	pub const GLOBAL_SHIFT: u32 = 48;
+	/// Bit offset of major in [`Version::to_u64`]
+	pub const MAJOR_SHIFT: u32 = 32;
+	/// Bit offset of minor in [`Version::to_u64`]
+	pub const MINOR_SHIFT: u32 = 16;
+	/// Bit offset of patch in [`Version::to_u64`]
+	pub const PATCH_SHIFT: u32 = 0;
+	/// Mask of a single component once shifted down
+	pub const COMPONENT_MASK: u64 = 0xFFFF;
+
 	pub const fn new(global: u16, major: u16, minor: u16, patch: u16) -> Self {
 		Self {
 			global,
@@ -22,80 +109,1335 @@ impl Version {
 		}
 	}
 
+	/// Like [`Version::new`], but returns `None` when the version would be above `max`
+	pub const fn checked_new(global: u16, major: u16, minor: u16, patch: u16, max: Version) -> Option<Version> {
+		let v = Self::new(global, major, minor, patch);
+		if v.gt(&max) {
+			None
+		} else {
+			Some(v)
+		}
+	}
+
+	/// Like [`Version::new`], but clamps each component to the matching component of `cap`
+	///
+	/// The clamping is component-wise rather than by total order, so `2.40.3.0` capped by `2.33.5.14` is `2.33.3.0`
+	pub const fn saturating_new(global: u16, major: u16, minor: u16, patch: u16, cap: Version) -> Version {
+		const fn min(a: u16, b: u16) -> u16 {
+			if a < b { a } else { b }
+		}
+		Self::new(min(global, cap.global), min(major, cap.major), min(minor, cap.minor), min(patch, cap.patch))
+	}
+
+	/// Starts a [`VersionBuilder`] defaulting to `2.0.0.0`
+	pub const fn builder() -> VersionBuilder {
+		VersionBuilder::new()
+	}
+
+	pub const fn global(&self) -> u16 {
+		self.global
+	}
+
+	pub const fn major(&self) -> u16 {
+		self.major
+	}
+
+	pub const fn minor(&self) -> u16 {
+		self.minor
+	}
+
+	pub const fn patch(&self) -> u16 {
+		self.patch
+	}
+
+	pub const fn with_global(self, global: u16) -> Version {
+		Self { global, ..self }
+	}
+
+	pub const fn with_major(self, major: u16) -> Version {
+		Self { major, ..self }
+	}
+
+	pub const fn with_minor(self, minor: u16) -> Version {
+		Self { minor, ..self }
+	}
+
+	pub const fn with_patch(self, patch: u16) -> Version {
+		Self { patch, ..self }
+	}
+
+	/// Forces global to 2, for reconciling upstream data that records it as `0`, `1` or similar
+	pub const fn normalize_global(self) -> Version {
+		self.with_global(2)
+	}
+
+	/// `[global, major, minor, patch]`
+	pub const fn components(&self) -> [u16; 4] {
+		[self.global, self.major, self.minor, self.patch]
+	}
+
+	/// `major.minor.patch`, the same as the alternate Display form `{:#}`
+	pub fn to_short_string(&self) -> String {
+		format!("{self:#}")
+	}
+
+	/// `global.major.minor.patch`, the same as Display
+	pub fn to_full_string(&self) -> String {
+		self.to_string()
+	}
+
+	/// Renders the `[global, major, minor, patch]` components with a custom `f`, such as `2_33_5_14` for filenames
+	pub fn format_with<F: Fn([u16; 4]) -> String>(&self, f: F) -> String {
+		f(self.components())
+	}
+
+	/// The release line `global.major`, such as `2.33`
+	pub fn line_string(&self) -> String {
+		format!("{}.{}", self.global, self.major)
+	}
+
+	/// Iterates global, major, minor and patch in order
+	pub fn iter_components(&self) -> impl Iterator<Item = u16> {
+		self.components().into_iter()
+	}
+
+	/// Applies `f` to global, major, minor and patch in order
+	pub fn map_components<F: FnMut(u16) -> u16>(&self, f: F) -> Version {
+		Self::from(self.components().map(f))
+	}
+
+	/// Builds a version from exactly four components in global, major, minor, patch order
+	///
+	/// Stops reading at a fifth component, so for longer input `TooManyComponents::found` is 5, only a lower bound of the real count
+	pub fn try_from_iter<I: IntoIterator<Item = u16>>(iter: I) -> Result<Version, ParseVersionError> {
+		let mut components = [0; 4];
+		let mut found = 0;
+		for component in iter {
+			if found == components.len() {
+				return Err(ParseVersionError::TooManyComponents { found: found + 1 });
+			}
+			components[found] = component;
+			found += 1;
+		}
+		if found < components.len() {
+			return Err(ParseVersionError::TooFewComponents { found });
+		}
+		Ok(Self::from(components))
+	}
+
+	/// Increments patch, saturating at `u16::MAX`
+	pub const fn bump_patch(self) -> Version {
+		match self.checked_bump_patch() {
+			Some(v) => v,
+			None => self,
+		}
+	}
+
+	/// Increments minor and resets patch
+	///
+	/// Returns the version unchanged when minor is already `u16::MAX`, so bumping never lowers a version
+	pub const fn bump_minor(self) -> Version {
+		match self.checked_bump_minor() {
+			Some(v) => v,
+			None => self,
+		}
+	}
+
+	/// Increments major and resets minor and patch
+	///
+	/// Returns the version unchanged when major is already `u16::MAX`, so bumping never lowers a version
+	pub const fn bump_major(self) -> Version {
+		match self.checked_bump_major() {
+			Some(v) => v,
+			None => self,
+		}
+	}
+
+	/// Increments global and resets all other components
+	///
+	/// Returns the version unchanged when global is already `u16::MAX`, so bumping never lowers a version
+	pub const fn bump_global(self) -> Version {
+		match self.checked_bump_global() {
+			Some(v) => v,
+			None => self,
+		}
+	}
+
+	/// Advances patch by `n`, returning `None` instead of rolling over into minor like [`Add<u64>`] does
+	pub const fn checked_add_patch(self, n: u16) -> Option<Version> {
+		match self.patch.checked_add(n) {
+			Some(patch) => Some(Self::new(self.global, self.major, self.minor, patch)),
+			None => None,
+		}
+	}
+
+	/// Increments the component at `level` and resets all less significant ones, see the individual `bump_*` methods
+	pub const fn bump(self, level: VersionLevel) -> Version {
+		match level {
+			VersionLevel::Global => self.bump_global(),
+			VersionLevel::Major => self.bump_major(),
+			VersionLevel::Minor => self.bump_minor(),
+			VersionLevel::Patch => self.bump_patch(),
+		}
+	}
+
+	/// Increments patch, returning `None` when it is already `u16::MAX`
+	pub const fn checked_bump_patch(self) -> Option<Version> {
+		match self.patch.checked_add(1) {
+			Some(patch) => Some(Self::new(self.global, self.major, self.minor, patch)),
+			None => None,
+		}
+	}
+
+	/// Increments minor and resets patch, returning `None` when minor is already `u16::MAX`
+	pub const fn checked_bump_minor(self) -> Option<Version> {
+		match self.minor.checked_add(1) {
+			Some(minor) => Some(Self::new(self.global, self.major, minor, 0)),
+			None => None,
+		}
+	}
+
+	/// Increments major and resets minor and patch, returning `None` when major is already `u16::MAX`
+	pub const fn checked_bump_major(self) -> Option<Version> {
+		match self.major.checked_add(1) {
+			Some(major) => Some(Self::new(self.global, major, 0, 0)),
+			None => None,
+		}
+	}
+
+	/// Increments global and resets all other components, returning `None` when global is already `u16::MAX`
+	pub const fn checked_bump_global(self) -> Option<Version> {
+		match self.global.checked_add(1) {
+			Some(global) => Some(Self::new(global, 0, 0, 0)),
+			None => None,
+		}
+	}
+
+	/// Returns the most significant component that differs between both versions, or `None` if they are equal
+	pub const fn diff(&self, other: &Version) -> Option<VersionLevel> {
+		if self.global != other.global {
+			Some(VersionLevel::Global)
+		} else if self.major != other.major {
+			Some(VersionLevel::Major)
+		} else if self.minor != other.minor {
+			Some(VersionLevel::Minor)
+		} else if self.patch != other.patch {
+			Some(VersionLevel::Patch)
+		} else {
+			None
+		}
+	}
+
+	/// Signed change of each component going from `self` to `other`, in `[global, major, minor, patch]` order
+	pub const fn delta_components(&self, other: &Version) -> [i32; 4] {
+		[
+			other.global as i32 - self.global as i32,
+			other.major as i32 - self.major as i32,
+			other.minor as i32 - self.minor as i32,
+			other.patch as i32 - self.patch as i32,
+		]
+	}
+
+	/// True when global, major and minor match and the patches are at most `tolerance` apart
+	pub const fn within_patch(&self, other: &Version, tolerance: u16) -> bool {
+		self.same_minor(other) && self.patch.abs_diff(other.patch) <= tolerance
+	}
+
+	pub const fn is_compatible_with(&self, other: &Version, policy: CompatibilityPolicy) -> bool {
+		match policy {
+			CompatibilityPolicy::Exact => self.to_u64() == other.to_u64(),
+			CompatibilityPolicy::SameMinor => self.same_minor(other),
+			CompatibilityPolicy::SameMajor => self.same_major(other),
+			CompatibilityPolicy::SameGlobal => self.global == other.global,
+		}
+	}
+
+	/// `self < other`, usable in const contexts unlike [`PartialOrd::lt`]
+	pub const fn lt(&self, other: &Version) -> bool {
+		self.to_u64() < other.to_u64()
+	}
+
+	/// `self > other`, usable in const contexts unlike [`PartialOrd::gt`]
+	pub const fn gt(&self, other: &Version) -> bool {
+		self.to_u64() > other.to_u64()
+	}
+
+	/// The overall ordering along with the most significant level that decided it, see [`Version::diff`]
+	pub fn cmp_verbose(&self, other: &Version) -> (Ordering, Option<VersionLevel>) {
+		(self.cmp(other), self.diff(other))
+	}
+
+	/// Keeps every component up to and including `level`, zeroing the less significant ones
+	pub const fn truncate_to(&self, level: VersionLevel) -> Version {
+		match level {
+			VersionLevel::Global => Self::new(self.global, 0, 0, 0),
+			VersionLevel::Major => Self::new(self.global, self.major, 0, 0),
+			VersionLevel::Minor => Self::new(self.global, self.major, self.minor, 0),
+			VersionLevel::Patch => *self,
+		}
+	}
+
+	/// True when `self` is strictly newer than the version in `s`
+	pub fn is_newer_than_str(&self, s: &str) -> Result<bool, ParseVersionError> {
+		Ok(*self > Version::from_str(s)?)
+	}
+
+	/// True when `self` is strictly older than the version in `s`
+	pub fn is_older_than_str(&self, s: &str) -> Result<bool, ParseVersionError> {
+		Ok(*self < Version::from_str(s)?)
+	}
+
+	/// True when global, major and minor match, ignoring patch
+	pub const fn same_minor(&self, other: &Version) -> bool {
+		self.global == other.global && self.major == other.major && self.minor == other.minor
+	}
+
+	/// True when global and major match, ignoring minor and patch
+	pub const fn same_major(&self, other: &Version) -> bool {
+		self.global == other.global && self.major == other.major
+	}
+
+	/// The half-open range `[global.major.0.0, global.(major + 1).0.0)` covering this release line
+	///
+	/// When major is `u16::MAX` the upper bound is `(global + 1).0.0.0` instead, saturating at [`Version::MAX`]
+	pub const fn major_line(&self) -> (Version, Version) {
+		let start = Self::new(self.global, self.major, 0, 0);
+		let end = match (self.major.checked_add(1), self.global.checked_add(1)) {
+			(Some(major), _) => Self::new(self.global, major, 0, 0),
+			(None, Some(global)) => Self::new(global, 0, 0, 0),
+			(None, None) => Self::MAX,
+		};
+		(start, end)
+	}
+
+	/// True when `candidate` is part of this release line, see [`Version::major_line`]
+	///
+	/// Agrees with the range, except that it also covers [`Version::MAX`], which the saturated upper bound cannot
+	pub const fn contains_in_line(&self, candidate: &Version) -> bool {
+		self.same_major(candidate)
+	}
+
+	/// Heuristic: major releases tend to end in `.0.0`
+	pub const fn is_major_release(&self) -> bool {
+		self.minor == 0 && self.patch == 0
+	}
+
+	/// Heuristic: hotfixes tend to bump patch
+	pub const fn is_hotfix(&self) -> bool {
+		self.patch != 0
+	}
+
 	pub const fn to_u64(&self) -> u64 {
-		((self.global as u64) << 48) | ((self.major as u64) << 32) | ((self.minor as u64) << 16) | (self.patch as u64)
+		((self.global as u64) << Self::GLOBAL_SHIFT)
+			| ((self.major as u64) << Self::MAJOR_SHIFT)
+			| ((self.minor as u64) << Self::MINOR_SHIFT)
+			| ((self.patch as u64) << Self::PATCH_SHIFT)
+	}
+
+	/// An identifier that stays the same across releases of this crate, suitable for persisted cache keys
+	///
+	/// Guaranteed to equal the current [`Version::to_u64`] layout, even if the fields or [`Hash`] impl change
+	pub const fn stable_id(&self) -> u64 {
+		self.to_u64()
+	}
+
+	/// Like [`Version::from_u64`], but returns `None` when the decoded global is not `expected_global`
+	///
+	/// Useful to catch corrupted or misinterpreted binary data
+	pub const fn from_u64_validated(value: u64, expected_global: u16) -> Option<Version> {
+		let v = Self::from_u64(value);
+		if v.global == expected_global {
+			Some(v)
+		} else {
+			None
+		}
+	}
+
+	pub const fn to_be_bytes(&self) -> [u8; 8] {
+		self.to_u64().to_be_bytes()
+	}
+
+	pub const fn to_le_bytes(&self) -> [u8; 8] {
+		self.to_u64().to_le_bytes()
+	}
+
+	pub const fn from_be_bytes(bytes: [u8; 8]) -> Version {
+		Self::from_u64(u64::from_be_bytes(bytes))
+	}
+
+	pub const fn from_le_bytes(bytes: [u8; 8]) -> Version {
+		Self::from_u64(u64::from_le_bytes(bytes))
+	}
+
+	/// Writes the big endian packing into `buf`, the same bytes as [`Version::to_be_bytes`]
+	pub fn encode_into(&self, buf: &mut [u8; 8]) {
+		*buf = self.to_be_bytes();
+	}
+
+	/// Reads a big endian packing written by [`Version::encode_into`]
+	pub const fn decode_from(buf: &[u8; 8]) -> Version {
+		Self::from_be_bytes(*buf)
+	}
+
+	/// The next representable version, rolling patch over into minor and so on
+	pub const fn successor(&self) -> Option<Version> {
+		match self.to_u64().checked_add(1) {
+			Some(value) => Some(Self::from_u64(value)),
+			None => None,
+		}
+	}
+
+	/// The previous representable version, rolling patch under into minor and so on
+	pub const fn predecessor(&self) -> Option<Version> {
+		match self.to_u64().checked_sub(1) {
+			Some(value) => Some(Self::from_u64(value)),
+			None => None,
+		}
+	}
+
+	/// Absolute difference between the packed representations of both versions
+	pub const fn distance(&self, other: &Version) -> u64 {
+		self.to_u64().abs_diff(other.to_u64())
+	}
+
+	/// Every version from `start` to `end` inclusive, rolling patch over into minor and so on
+	///
+	/// Empty when `start > end`
+	pub fn patch_range(start: Version, end: Version) -> impl Iterator<Item = Version> {
+		Self::range(start, end)
+	}
+
+	/// Every version from `start` to `end` inclusive, iterable from both ends
+	///
+	/// Empty when `start > end`
+	pub const fn range(start: Version, end: Version) -> VersionRange {
+		VersionRange::new(start, end)
+	}
+
+	/// The candidate with the smallest [`Version::distance`], preferring the lower version on ties
+	pub fn nearest<'a>(&self, candidates: &'a [Version]) -> Option<&'a Version> {
+		candidates.iter().min_by_key(|candidate| (self.distance(candidate), **candidate))
+	}
+
+	/// The highest version yielded, or `None` when empty
+	pub fn max_of<I: IntoIterator<Item = Version>>(iter: I) -> Option<Version> {
+		iter.into_iter().max()
+	}
+
+	/// The lowest version yielded, or `None` when empty
+	pub fn min_of<I: IntoIterator<Item = Version>>(iter: I) -> Option<Version> {
+		iter.into_iter().min()
+	}
+
+	/// Sorts ascending by the canonical ordering and removes duplicates
+	pub fn sort_dedup(versions: &mut Vec<Version>) {
+		versions.sort_unstable();
+		versions.dedup();
+	}
+
+	/// The entry with the greatest key less than or equal to `query`
+	pub fn floor_in<'a, V>(map: &'a BTreeMap<Version, V>, query: &Version) -> Option<(&'a Version, &'a V)> {
+		map.range(..=query).next_back()
+	}
+
+	/// The entry with the smallest key greater than or equal to `query`
+	pub fn ceil_in<'a, V>(map: &'a BTreeMap<Version, V>, query: &Version) -> Option<(&'a Version, &'a V)> {
+		map.range(query..).next()
+	}
+
+	/// Constrains the version into the inclusive range `min..=max`
+	pub fn clamp_to(self, min: Version, max: Version) -> Version {
+		debug_assert!(min <= max, "min {min} is greater than max {max}");
+		if self < min {
+			min
+		} else if self > max {
+			max
+		} else {
+			self
+		}
+	}
+
+	/// True when `self` lies in `range`, including both bounds
+	pub fn within(&self, range: RangeInclusive<Version>) -> bool {
+		range.contains(self)
+	}
+
+	/// True when `self` lies in the half-open `range`, so the end itself is excluded
+	pub fn within_exclusive(&self, range: Range<Version>) -> bool {
+		range.contains(self)
 	}
 
 	pub const fn from_u64(value: u64) -> Version {
 		Version {
-			global: ((value >> 48) & 0xFFFF) as u16,
-			major: ((value >> 32) & 0xFFFF) as u16,
-			minor: ((value >> 16) & 0xFFFF) as u16,
-			patch: (value & 0xFFFF) as u16,
+			global: ((value >> Self::GLOBAL_SHIFT) & Self::COMPONENT_MASK) as u16,
+			major: ((value >> Self::MAJOR_SHIFT) & Self::COMPONENT_MASK) as u16,
+			minor: ((value >> Self::MINOR_SHIFT) & Self::COMPONENT_MASK) as u16,
+			patch: ((value >> Self::PATCH_SHIFT) & Self::COMPONENT_MASK) as u16,
 		}
 	}
-	pub fn is_valid(s: &str) -> bool {
-		let s = s.split(".").collect::<Vec<_>>();
-		if s.len() != 3 {
-			return false;
+
+	/// Like [`FromStr`], but also accepts `33.5.14` by assuming global is 2
+	pub fn parse_lenient(s: &str) -> Result<Version, ParseVersionError> {
+		let c = parse_components(s, '.', 3, 4)?;
+		match c.as_slice() {
+			&[major, minor, patch] => Ok(Self::new(2, major, minor, patch)),
+			_ => Ok(Self::new(c[0], c[1], c[2], c[3])),
+		}
+	}
+
+	/// Three components are `major.minor.patch` with global omitted as 2, four are taken as-is
+	///
+	/// The same rule as [`Version::parse_lenient`], unlike [`Version::parse_padded`] the missing component is the leading one
+	pub fn parse_omit_global(s: &str) -> Result<Version, ParseVersionError> {
+		Self::parse_lenient(s)
+	}
+
+	/// Like [`FromStr`], but only accepts canonical components, rejecting signs such as `2.+33.5.14` and leading zeros such as `2.033.5.14`
+	pub fn from_str_exact(s: &str) -> Result<Version, ParseVersionError> {
+		let split = split_components(s.trim(), '.', 4, 4)?;
+		// `u16::from_str` accepts a leading `+`, which is not canonical either
+		if let Some(index) = split.iter().position(|e| !e.bytes().all(|b| b.is_ascii_digit())) {
+			return Err(ParseVersionError::NonDigit { index });
+		}
+		if let Some(index) = split.iter().position(|e| e.len() > 1 && e.starts_with('0')) {
+			return Err(ParseVersionError::LeadingZero { index });
+		}
+		Self::from_str(s)
+	}
+
+	/// Like [`FromStr`], but accepts 1 to 4 components and fills missing trailing ones with 0, so `2.33` is `2.33.0.0`
+	pub fn parse_padded(s: &str) -> Result<Version, ParseVersionError> {
+		let mut c = [0; 4];
+		for (i, component) in parse_components(s, '.', 1, 4)?.into_iter().enumerate() {
+			c[i] = component;
+		}
+		Ok(Self::from(c))
+	}
+
+	/// Parses a release line such as `2.33` into `2.33.0.0`, the inverse of [`Version::line_string`]
+	pub fn parse_line(s: &str) -> Result<Version, ParseVersionError> {
+		let c = parse_components(s, '.', 2, 2)?;
+		Ok(Self::new(c[0], c[1], 0, 0))
+	}
+
+	/// Like [`FromStr`], but with components in `radix`, so `2.21.5.e` in radix 16 is `2.33.5.14`
+	///
+	/// # Panics
+	/// When `radix` is not in `2..=36`
+	pub fn parse_radix(s: &str, radix: u32) -> Result<Version, ParseVersionError> {
+		let c = parse_components_radix(s, '.', radix, 4, 4)?;
+		Ok(Self::new(c[0], c[1], c[2], c[3]))
+	}
+
+	/// Like [`FromStr`], but splits on `sep` instead of `.`
+	pub fn parse_with_separator(s: &str, sep: char) -> Result<Version, ParseVersionError> {
+		let c = parse_components(s, sep, 4, 4)?;
+		Ok(Self::new(c[0], c[1], c[2], c[3]))
+	}
+
+	/// Like [`Version::parse_with_separator`], but detects whether `.`, `-` or `_` is used
+	pub fn parse_flexible(s: &str) -> Result<Version, ParseVersionError> {
+		let mut parsed = ['.', '-', '_'].into_iter().filter_map(|sep| Self::parse_with_separator(s, sep).ok());
+		match (parsed.next(), parsed.next()) {
+			(Some(v), None) => Ok(v),
+			(Some(a), Some(b)) if a == b && parsed.all(|c| c == a) => Ok(a),
+			_ => Err(ParseVersionError::AmbiguousSeparator),
+		}
+	}
+
+	/// Strips `prefix` such as `v` or `War Thunder ` and parses the remainder
+	pub fn parse_prefixed(s: &str, prefix: &str) -> Result<Version, ParseVersionError> {
+		let rest = s.strip_prefix(prefix).ok_or_else(|| ParseVersionError::MissingPrefix { prefix: prefix.into() })?;
+		Self::from_str(rest).map_err(|e| e.offset_span(prefix.len()))
+	}
+
+	/// Parses `git describe` output such as `v2.33.5.14-5-g1a2b3c`, ignoring an optional leading `v` and everything from the first `-`
+	pub fn parse_git_describe(s: &str) -> Result<Version, ParseVersionError> {
+		let trimmed = s.trim();
+		let tag = trimmed.strip_prefix('v').unwrap_or(trimmed).split('-').next().unwrap_or_default();
+		Self::from_str(tag).map_err(|e| e.offset_span(span_of(s, tag).start))
+	}
+
+	/// Parses the last whitespace or underscore delimited token, such as in `wt_update_2.33.5.14`
+	pub fn parse_suffix(s: &str) -> Result<Version, ParseVersionError> {
+		let tail = s.trim_end().rsplit(|c: char| c.is_whitespace() || c == '_').next().unwrap_or_default();
+		Self::from_str(tail).map_err(|e| e.offset_span(span_of(s, tail).start))
+	}
+
+	/// Like [`FromStr`], but reports how many components were seen alongside a failure
+	pub fn parse_counted(s: &str) -> Result<Version, (usize, ParseVersionError)> {
+		Self::from_str(s).map_err(|e| {
+			let s = s.trim();
+			let count = if s.is_empty() { 0 } else { s.split('.').count() };
+			(count, e)
+		})
+	}
+
+	/// Like [`FromStr`], but also fails when the version lies outside `min..=max`
+	pub fn parse_bounded(s: &str, min: Version, max: Version) -> Result<Version, ParseVersionError> {
+		let value = Self::from_str(s)?;
+		if value < min || value > max {
+			return Err(ParseVersionError::OutOfRange { value, min, max });
+		}
+		Ok(value)
+	}
+
+	/// Like [`FromStr`], but treats an empty string, `none` or `any` in any case as unset
+	pub fn parse_optional(s: &str) -> Result<Option<Version>, ParseVersionError> {
+		let trimmed = s.trim();
+		if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("none") || trimmed.eq_ignore_ascii_case("any") {
+			return Ok(None);
+		}
+		Self::from_str(s).map(Some)
+	}
+
+	/// Like [`FromStr`], but never fails, the flag tells whether parsing succeeded
+	///
+	/// Deliberately lossy, any malformed input becomes [`Version::MIN`] and the error is discarded
+	pub fn parse_best_effort(s: &str) -> (Version, bool) {
+		match Self::from_str(s) {
+			Ok(v) => (v, true),
+			Err(_) => (Self::MIN, false),
+		}
+	}
+
+	/// Parses one version per line, skipping blank lines
+	///
+	/// Fails on the first invalid line, returning its 1-based line number with the error
+	pub fn parse_lines(input: &str) -> Result<Vec<Version>, (usize, ParseVersionError)> {
+		input
+			.lines()
+			.enumerate()
+			.filter(|(_, line)| !line.trim().is_empty())
+			.map(|(i, line)| Self::from_str(line).map_err(|e| (i + 1, e)))
+			.collect()
+	}
+
+	/// Parses one version per line like [`Version::parse_lines`], but keeps going past invalid lines
+	///
+	/// Failures are collected with their 1-based line number instead
+	pub fn parse_all(input: &str) -> (Vec<Version>, Vec<(usize, ParseVersionError)>) {
+		let mut versions = Vec::new();
+		let mut errors = Vec::new();
+		for (i, line) in input.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+			match Self::from_str(line) {
+				Ok(version) => versions.push(version),
+				Err(e) => errors.push((i + 1, e)),
+			}
+		}
+		(versions, errors)
+	}
+
+	/// Lazily parses one version per line like [`Version::parse_lines`], without reading everything up front
+	///
+	/// Blank lines are skipped, failures carry their 1-based line number
+	#[cfg(feature = "std")]
+	pub fn parse_reader<R: std::io::BufRead>(reader: R) -> impl Iterator<Item = Result<Version, (usize, ParseVersionError)>> {
+		reader
+			.lines()
+			.enumerate()
+			.filter(|(_, line)| line.as_ref().map_or(true, |line| !line.trim().is_empty()))
+			.map(|(i, line)| {
+				let line = line.map_err(|e| (i + 1, ParseVersionError::Io { kind: e.kind() }))?;
+				Self::from_str(&line).map_err(|e| (i + 1, e))
+			})
+	}
+
+	/// Lazily yields every whitespace separated token that parses as a version, skipping all others
+	pub fn parse_iter(s: &str) -> impl Iterator<Item = Version> + '_ {
+		s.split_whitespace().filter_map(|token| Self::from_str(token).ok())
+	}
+
+	/// Matches against a pattern like `2.33.*.*`, where `*` matches any value in that position
+	pub fn matches_pattern(&self, pattern: &str) -> Result<bool, ParseVersionError> {
+		let mut matches = true;
+		for (index, (e, component)) in split_components(pattern, '.', 4, 4)?.into_iter().zip(self.components()).enumerate() {
+			if e == "*" {
+				continue;
+			}
+			let expected = u16::from_str(e)
+				.map_err(|source| ParseVersionError::InvalidComponent { index, span: span_of(pattern, e), source })?;
+			matches &= expected == component;
 		}
+		Ok(matches)
+	}
 
-		s.into_iter().all(|e|u16::from_str(e).is_ok())
+	/// True when [`FromStr`] would accept `s`
+	pub fn is_valid(s: &str) -> bool {
+		Version::from_str(s).is_ok()
 	}
 }
 
+/// The alternate form `{:#}` omits global, printing `33.5.14`
+///
+/// A width zero-pads every component to it, so `{:3}` prints `002.033.005.014`
 impl Display for Version {
-	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-		write!(f, "{}.{}.{}.{}", self.global, self.major, self.minor, self.patch)
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		let w = f.width().unwrap_or(0);
+		if f.alternate() {
+			write!(f, "{:0w$}.{:0w$}.{:0w$}", self.major, self.minor, self.patch)
+		} else {
+			write!(f, "{:0w$}.{:0w$}.{:0w$}.{:0w$}", self.global, self.major, self.minor, self.patch)
+		}
 	}
 }
 
 
-impl PartialOrd<Self> for Version {
-	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-		Some(self.to_u64().cmp(&other.to_u64()))
-	}
+/// Reasons a version string can fail to parse
+///
+/// Non-exhaustive, as variants such as `Io` only exist with the `std` feature, which any crate in the build may enable
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseVersionError {
+	/// The input string was empty
+	Empty,
+	/// Fewer components than required were present, usually four
+	TooFewComponents { found: usize },
+	/// More components than allowed were present, usually four
+	TooManyComponents { found: usize },
+	/// The component at `index`, found at the byte range `span` of the input, is not a valid `u16`
+	InvalidComponent { index: usize, span: Range<usize>, source: ParseIntError },
+	/// The input did not start with the expected prefix
+	MissingPrefix { prefix: String },
+	/// The component at `index` has a leading zero, such as `033`
+	LeadingZero { index: usize },
+	/// The component at `index` contains something other than ASCII digits, such as `+33`
+	NonDigit { index: usize },
+	/// None, or several of the separators tried yield conflicting versions
+	AmbiguousSeparator,
+	/// The version parsed fine but lies outside `min..=max`
+	OutOfRange { value: Version, min: Version, max: Version },
+	/// Reading the input failed
+	#[cfg(feature = "std")]
+	Io { kind: std::io::ErrorKind },
 }
 
-impl Ord for Version {
-	fn cmp(&self, other: &Self) -> Ordering {
-		self.to_u64().cmp(&other.to_u64())
+impl Display for ParseVersionError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			ParseVersionError::Empty => write!(f, "version string is empty"),
+			ParseVersionError::TooFewComponents { found } => write!(f, "too few components, found {found}"),
+			ParseVersionError::TooManyComponents { found } => write!(f, "too many components, found {found}"),
+			ParseVersionError::InvalidComponent { index, source, .. } => write!(f, "component {index} is invalid: {source}"),
+			ParseVersionError::MissingPrefix { prefix } => write!(f, "expected prefix {prefix:?}"),
+			ParseVersionError::LeadingZero { index } => write!(f, "component {index} has a leading zero"),
+			ParseVersionError::NonDigit { index } => write!(f, "component {index} contains a non-digit character"),
+			ParseVersionError::AmbiguousSeparator => write!(f, "no single separator yields a valid version"),
+			ParseVersionError::OutOfRange { value, min, max } => write!(f, "version {value} is outside of {min}..={max}"),
+			#[cfg(feature = "std")]
+			ParseVersionError::Io { kind } => write!(f, "failed to read input: {kind}"),
+		}
 	}
 }
 
-impl FromStr for Version {
-	type Err = ();
+impl ParseVersionError {
+	/// Shifts spans by `by` bytes, for errors from parsing a substring starting at offset `by`
+	fn offset_span(self, by: usize) -> Self {
+		match self {
+			ParseVersionError::InvalidComponent { index, span, source } => {
+				ParseVersionError::InvalidComponent { index, span: span.start + by..span.end + by, source }
+			}
+			other => other,
+		}
+	}
+}
 
-	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let mut split = s.split(".").map(|e|u16::from_str(e).map_err(|_|()));
-		let mut next = || split.next().ok_or(());
-		Ok(Self {
-			global: next()??,
-			major:  next()??,
-			minor:  next()??,
-			patch:  next()??,
-		})
+#[cfg(feature = "std")]
+impl std::error::Error for ParseVersionError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			ParseVersionError::InvalidComponent { source, .. } => Some(source),
+			_ => None,
+		}
 	}
 }
 
-#[cfg(test)]
-mod tests {
-	use super::*;
+/// A packed `u64` decoded to a global of 0, which no real version has and usually signals a misread
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct FromU64Error {
+	value: u64,
+}
 
-	#[test]
-	fn display_impl() {
-		assert_eq!("2.33.5.14", Version::new(2, 33, 5, 14).to_string().as_str())
+impl FromU64Error {
+	/// The rejected packed value
+	pub const fn value(&self) -> u64 {
+		self.value
 	}
+}
 
-	#[test]
-	fn from_str_impl() {
-		assert_eq!(Version::from_str("2.33.5.14").unwrap(), Version::new(2, 33, 5, 14))
+impl Display for FromU64Error {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "packed version {:#018x} has a global of 0", self.value)
 	}
+}
 
-	#[test]
-	#[should_panic]
-	fn empty_str() {
-		Version::from_str("").unwrap();
+#[cfg(feature = "std")]
+impl std::error::Error for FromU64Error {}
+
+/// Defaults to `2.0.0.0`, as global is pretty much always 2
+impl Default for Version {
+	fn default() -> Self {
+		Self::new(2, 0, 0, 0)
+	}
+}
+
+/// Compares against the parsed string, an unparseable string is never equal
+impl PartialEq<str> for Version {
+	fn eq(&self, other: &str) -> bool {
+		Version::from_str(other).is_ok_and(|other| *self == other)
+	}
+}
+
+impl PartialEq<&str> for Version {
+	fn eq(&self, other: &&str) -> bool {
+		*self == **other
+	}
+}
+
+impl PartialEq<Version> for str {
+	fn eq(&self, other: &Version) -> bool {
+		*other == *self
+	}
+}
+
+impl PartialEq<Version> for &str {
+	fn eq(&self, other: &Version) -> bool {
+		*other == **self
+	}
+}
+
+/// Compares against a packed [`Version::to_u64`]
+impl PartialEq<u64> for Version {
+	fn eq(&self, other: &u64) -> bool {
+		self.to_u64() == *other
+	}
+}
+
+impl PartialEq<Version> for u64 {
+	fn eq(&self, other: &Version) -> bool {
+		*self == other.to_u64()
+	}
+}
+
+/// Compares against a packed [`Version::to_u64`]
+impl PartialOrd<u64> for Version {
+	fn partial_cmp(&self, other: &u64) -> Option<Ordering> {
+		Some(self.to_u64().cmp(other))
+	}
+}
+
+impl PartialOrd<Version> for u64 {
+	fn partial_cmp(&self, other: &Version) -> Option<Ordering> {
+		Some(self.cmp(&other.to_u64()))
+	}
+}
+
+/// Hashes the packed [`Version::to_u64`] in a single write
+///
+/// Consistent with [`Eq`], as `to_u64` is a bijection over the components
+impl Hash for Version {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.to_u64().hash(state);
+	}
+}
+
+/// Always `Some`, the order is total and agrees with [`Ord`]
+impl PartialOrd<Self> for Version {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+/// Advances by `rhs` on the packed [`Version::to_u64`], so `+ 1` is the next patch
+///
+/// Overflowing a component rolls over into the next more significant one, `2.33.5.65535 + 1` is `2.33.6.0`.
+/// Saturates at [`Version::MAX`]
+impl Add<u64> for Version {
+	type Output = Version;
+
+	fn add(self, rhs: u64) -> Self::Output {
+		Self::from_u64(self.to_u64().saturating_add(rhs))
+	}
+}
+
+/// Steps back by `rhs` on the packed [`Version::to_u64`], so `- 1` is the previous patch
+///
+/// Underflowing a component borrows from the next more significant one, `2.33.6.0 - 1` is `2.33.5.65535`.
+/// Saturates at [`Version::MIN`]
+impl Sub<u64> for Version {
+	type Output = Version;
+
+	fn sub(self, rhs: u64) -> Self::Output {
+		Self::from_u64(self.to_u64().saturating_sub(rhs))
+	}
+}
+
+/// A total order, consistent with comparing [`Version::to_u64`]
+///
+/// Compares the packed representations rather than field by field. In `benches/cmp.rs` field by field wins on
+/// uniformly random pairs, which mostly differ in global, but loses on realistic pairs sharing global and major,
+/// where the packed compare stays a single `u64` comparison. Real version sets look like the latter
+impl Ord for Version {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.to_u64().cmp(&other.to_u64())
+	}
+}
+
+impl FromStr for Version {
+	type Err = ParseVersionError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::parse_with_separator(s, '.')
+	}
+}
+
+impl TryFrom<&str> for Version {
+	type Error = ParseVersionError;
+
+	fn try_from(value: &str) -> Result<Self, Self::Error> {
+		Self::from_str(value)
+	}
+}
+
+impl TryFrom<String> for Version {
+	type Error = ParseVersionError;
+
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		Self::from_str(&value)
+	}
+}
+
+/// Like [`Version::from_u64`], but rejects a global of 0
+impl TryFrom<u64> for Version {
+	type Error = FromU64Error;
+
+	fn try_from(value: u64) -> Result<Self, Self::Error> {
+		let v = Self::from_u64(value);
+		if v.global == 0 {
+			return Err(FromU64Error { value });
+		}
+		Ok(v)
+	}
+}
+
+impl From<[u16; 4]> for Version {
+	fn from([global, major, minor, patch]: [u16; 4]) -> Self {
+		Self::new(global, major, minor, patch)
+	}
+}
+
+impl From<Version> for [u16; 4] {
+	fn from(value: Version) -> Self {
+		value.components()
+	}
+}
+
+impl From<(u16, u16, u16, u16)> for Version {
+	fn from((global, major, minor, patch): (u16, u16, u16, u16)) -> Self {
+		Self::new(global, major, minor, patch)
+	}
+}
+
+impl From<Version> for (u16, u16, u16, u16) {
+	fn from(value: Version) -> Self {
+		(value.global, value.major, value.minor, value.patch)
+	}
+}
+
+impl Index<VersionLevel> for Version {
+	type Output = u16;
+
+	fn index(&self, level: VersionLevel) -> &Self::Output {
+		match level {
+			VersionLevel::Global => &self.global,
+			VersionLevel::Major => &self.major,
+			VersionLevel::Minor => &self.minor,
+			VersionLevel::Patch => &self.patch,
+		}
+	}
+}
+
+/// Parses both strings and compares them, failing with the first parse error
+pub fn compare_strings(a: &str, b: &str) -> Result<Ordering, ParseVersionError> {
+	Ok(Version::from_str(a)?.cmp(&Version::from_str(b)?))
+}
+
+/// Trims `s`, splits it on `sep` and parses each decimal component, requiring between `min` and `max` components
+///
+/// Spans of invalid components stay relative to the untrimmed `s`
+fn parse_components(s: &str, sep: char, min: usize, max: usize) -> Result<Vec<u16>, ParseVersionError> {
+	parse_components_radix(s, sep, 10, min, max)
+}
+
+/// Like [`parse_components`], but with components in `radix`
+fn parse_components_radix(s: &str, sep: char, radix: u32, min: usize, max: usize) -> Result<Vec<u16>, ParseVersionError> {
+	split_components(s.trim(), sep, min, max)?.into_iter().enumerate().map(|(index, e)| {
+		u16::from_str_radix(e, radix).map_err(|source| ParseVersionError::InvalidComponent { index, span: span_of(s, e), source })
+	}).collect()
+}
+
+/// Byte range of `part` within `whole`, which it must be a subslice of
+fn span_of(whole: &str, part: &str) -> Range<usize> {
+	let start = part.as_ptr() as usize - whole.as_ptr() as usize;
+	start..start + part.len()
+}
+
+/// Splits `s` on `sep`, requiring between `min` and `max` components
+fn split_components(s: &str, sep: char, min: usize, max: usize) -> Result<Vec<&str>, ParseVersionError> {
+	if s.is_empty() {
+		return Err(ParseVersionError::Empty);
+	}
+
+	let split = s.split(sep).collect::<Vec<_>>();
+	match split.len() {
+		found if found < min => Err(ParseVersionError::TooFewComponents { found }),
+		found if found > max => Err(ParseVersionError::TooManyComponents { found }),
+		_ => Ok(split),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn display_impl() {
+		assert_eq!("2.33.5.14", Version::new(2, 33, 5, 14).to_string().as_str())
+	}
+
+	#[test]
+	fn display_alternate() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(format!("{}", v), "2.33.5.14");
+		assert_eq!(format!("{:#}", v), "33.5.14");
+	}
+
+	#[test]
+	fn checked_new() {
+		let max = Version::new(2, 40, 0, 0);
+		assert_eq!(Version::checked_new(2, 33, 5, 14, max), Some(Version::new(2, 33, 5, 14)));
+		assert_eq!(Version::checked_new(2, 40, 0, 0, max), Some(max));
+		assert_eq!(Version::checked_new(u16::MAX, u16::MAX, u16::MAX, u16::MAX, max), None);
+	}
+
+	#[test]
+	fn saturating_new() {
+		let cap = Version::new(2, 33, 5, 14);
+		assert_eq!(Version::saturating_new(3, 40, 9, 99, cap), cap);
+		assert_eq!(Version::saturating_new(2, 40, 3, 99, cap), Version::new(2, 33, 3, 14));
+		assert_eq!(Version::saturating_new(1, 2, 3, 4, cap), Version::new(1, 2, 3, 4));
+	}
+
+	#[test]
+	fn accessors() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v.global(), 2);
+		assert_eq!(v.major(), 33);
+		assert_eq!(v.minor(), 5);
+		assert_eq!(v.patch(), 14);
+	}
+
+	#[test]
+	fn short_full_string() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v.to_short_string(), "33.5.14");
+		assert_eq!(v.to_full_string(), "2.33.5.14");
+	}
+
+	#[test]
+	fn line_string() {
+		assert_eq!(Version::new(2, 33, 5, 14).line_string(), "2.33")
+	}
+
+	#[test]
+	fn format_with() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v.format_with(|[g, ma, mi, p]| format!("v{g}.{ma}.{mi}.{p}")), "v2.33.5.14");
+		assert_eq!(v.format_with(|c| c.map(|c| c.to_string()).join("_")), "2_33_5_14");
+	}
+
+	#[test]
+	fn parse_line() {
+		assert_eq!(Version::parse_line("2.33").unwrap(), Version::new(2, 33, 0, 0));
+		assert_eq!(Version::parse_line("2.33.5"), Err(ParseVersionError::TooManyComponents { found: 3 }));
+	}
+
+	#[test]
+	fn display_width() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(format!("{:2}", v), "02.33.05.14");
+		assert_eq!(format!("{:03}", v), "002.033.005.014");
+		assert_eq!(format!("{:#3}", v), "033.005.014");
+		assert_eq!(format!("{:1}", Version::new(2, 33, 5, 14)), "2.33.5.14");
+	}
+
+	#[test]
+	fn with_setters() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v.with_global(3), Version::new(3, 33, 5, 14));
+		assert_eq!(v.with_major(34), Version::new(2, 34, 5, 14));
+		assert_eq!(v.with_minor(6), Version::new(2, 33, 6, 14));
+		assert_eq!(v.with_patch(99), Version::new(2, 33, 5, 99));
+	}
+
+	#[test]
+	fn normalize_global() {
+		assert_eq!(Version::new(0, 33, 5, 14).normalize_global(), Version::new(2, 33, 5, 14));
+		assert_eq!(Version::new(2, 33, 5, 14).normalize_global(), Version::new(2, 33, 5, 14));
+	}
+
+	#[test]
+	fn components() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v.components(), [2, 33, 5, 14]);
+		assert_eq!(v.iter_components().collect::<Vec<_>>(), [2, 33, 5, 14]);
+	}
+
+	#[test]
+	fn map_components() {
+		assert_eq!(Version::new(2, 33, 5, 14).map_components(|c| c.min(10)), Version::new(2, 10, 5, 10))
+	}
+
+	#[test]
+	fn const_context() {
+		const V: Version = Version::new(2, 33, 5, 14);
+		const PACKED: u64 = V.to_u64();
+		const UNPACKED: Version = Version::from_u64(PACKED);
+		assert_eq!(V, UNPACKED);
+	}
+
+	/// Values around every bit boundary of a component
+	const BOUNDARIES: [u16; 9] = [0, 1, 2, 0xFF, 0x100, 0x7FFF, 0x8000, u16::MAX - 1, u16::MAX];
+
+	#[test]
+	fn layout_constants() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v.to_u64(), (2 << 48) | (33 << 32) | (5 << 16) | 14);
+		assert_eq!((v.to_u64() >> Version::MAJOR_SHIFT) & Version::COMPONENT_MASK, 33);
+		assert_eq!(
+			(2 << Version::GLOBAL_SHIFT) | (33 << Version::MAJOR_SHIFT) | (5 << Version::MINOR_SHIFT) | (14 << Version::PATCH_SHIFT),
+			v.to_u64()
+		);
+	}
+
+	#[test]
+	fn u64_round_trip() {
+		for global in BOUNDARIES {
+			for major in BOUNDARIES {
+				for minor in BOUNDARIES {
+					for patch in BOUNDARIES {
+						let v = Version::new(global, major, minor, patch);
+						assert_eq!(Version::from_u64(v.to_u64()), v);
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn from_u64_validated() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(Version::from_u64_validated(v.to_u64(), 2), Some(v));
+		assert_eq!(Version::from_u64_validated(v.to_u64(), 1), None);
+		assert_eq!(Version::from_u64_validated(Version::new(7, 33, 5, 14).to_u64(), 2), None);
+	}
+
+	#[test]
+	fn be_bytes_round_trip() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v.to_be_bytes(), [0, 2, 0, 33, 0, 5, 0, 14]);
+		assert_eq!(Version::from_be_bytes(v.to_be_bytes()), v);
+	}
+
+	#[test]
+	fn le_bytes_round_trip() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v.to_le_bytes(), [14, 0, 5, 0, 33, 0, 2, 0]);
+		assert_eq!(Version::from_le_bytes(v.to_le_bytes()), v);
+	}
+
+	#[test]
+	fn display_from_str_round_trip() {
+		let boundaries = BOUNDARIES.into_iter().flat_map(|global| {
+			BOUNDARIES.into_iter().flat_map(move |major| {
+				BOUNDARIES.into_iter().flat_map(move |minor| {
+					BOUNDARIES.into_iter().map(move |patch| Version::new(global, major, minor, patch))
+				})
+			})
+		});
+		// Fixed seed xorshift, so failures are reproducible
+		let mut state = 0x2_0021_0005_000E_u64;
+		let random = std::iter::repeat_with(move || {
+			state ^= state << 13;
+			state ^= state >> 7;
+			state ^= state << 17;
+			Version::from_u64(state)
+		}).take(10_000);
+
+		for v in boundaries.chain(random) {
+			assert_eq!(Version::from_str(&v.to_string()), Ok(v));
+		}
+	}
+
+	#[test]
+	fn encode_decode() {
+		let v = Version::new(2, 33, 5, 14);
+		let mut buf = [0; 8];
+		v.encode_into(&mut buf);
+		assert_eq!(buf, v.to_be_bytes());
+		assert_eq!(Version::decode_from(&buf), v);
+	}
+
+	#[test]
+	fn from_str_impl() {
+		assert_eq!(Version::from_str("2.33.5.14").unwrap(), Version::new(2, 33, 5, 14))
+	}
+
+	#[test]
+	fn try_from_str() {
+		assert_eq!(Version::try_from("2.33.5.14").unwrap(), Version::new(2, 33, 5, 14))
+	}
+
+	#[test]
+	fn try_from_string() {
+		assert_eq!(Version::try_from("2.33.5.14".to_owned()).unwrap(), Version::new(2, 33, 5, 14))
+	}
+
+	#[test]
+	fn try_from_u64() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(Version::try_from(v.to_u64()), Ok(v));
+	}
+
+	#[test]
+	fn try_from_u64_zero_global() {
+		let value = Version::new(0, 33, 5, 14).to_u64();
+		assert_eq!(Version::try_from(value), Err(FromU64Error { value }));
+		assert_eq!(FromU64Error { value }.to_string(), "packed version 0x000000210005000e has a global of 0");
+	}
+
+	#[test]
+	fn from_array() {
+		assert_eq!(Version::from([2, 33, 5, 14]), Version::new(2, 33, 5, 14))
+	}
+
+	#[test]
+	fn try_from_iter() {
+		assert_eq!(Version::try_from_iter(vec![2, 33, 5, 14]), Ok(Version::new(2, 33, 5, 14)));
+		assert_eq!(Version::try_from_iter([2, 33, 5]), Err(ParseVersionError::TooFewComponents { found: 3 }));
+		assert_eq!(Version::try_from_iter([]), Err(ParseVersionError::TooFewComponents { found: 0 }));
+		assert_eq!(Version::try_from_iter([2, 33, 5, 14, 1, 0]), Err(ParseVersionError::TooManyComponents { found: 5 }));
+		assert_eq!(Version::try_from_iter(std::iter::repeat(0)), Err(ParseVersionError::TooManyComponents { found: 5 }));
+	}
+
+	#[test]
+	fn into_array() {
+		assert_eq!(<[u16; 4]>::from(Version::new(2, 33, 5, 14)), [2, 33, 5, 14])
+	}
+
+	#[test]
+	fn tuple_round_trip() {
+		let v = Version::from((2, 33, 5, 14));
+		assert_eq!(v, Version::new(2, 33, 5, 14));
+		assert_eq!(<(u16, u16, u16, u16)>::from(v), (2, 33, 5, 14))
+	}
+
+	#[test]
+	fn level_iter() {
+		assert_eq!(VersionLevel::iter().collect::<Vec<_>>(), [
+			VersionLevel::Global,
+			VersionLevel::Major,
+			VersionLevel::Minor,
+			VersionLevel::Patch,
+		]);
+		assert!(VersionLevel::iter().is_sorted());
+	}
+
+	#[test]
+	fn level_ord() {
+		assert!(VersionLevel::Global < VersionLevel::Patch);
+		assert!(VersionLevel::Major < VersionLevel::Minor);
+	}
+
+	#[test]
+	fn index_by_level() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v[VersionLevel::Global], 2);
+		assert_eq!(v[VersionLevel::Major], 33);
+		assert_eq!(v[VersionLevel::Minor], 5);
+		assert_eq!(v[VersionLevel::Patch], 14);
+	}
+
+	#[test]
+	fn surrounding_whitespace_variants() {
+		assert_eq!(Version::parse_lenient(" 33.5.14"), Ok(Version::new(2, 33, 5, 14)));
+		assert_eq!(Version::parse_padded("2.33 "), Ok(Version::new(2, 33, 0, 0)));
+		assert_eq!(Version::parse_line("\t2.33\n"), Ok(Version::new(2, 33, 0, 0)));
+		assert_eq!(Version::parse_radix(" 2.21.5.e ", 16), Ok(Version::new(2, 33, 5, 14)));
+		assert_eq!(Version::parse_with_separator(" 2_33_5_14", '_'), Ok(Version::new(2, 33, 5, 14)));
+
+		let s = "  33.5x.14";
+		let Err(ParseVersionError::InvalidComponent { span, .. }) = Version::parse_lenient(s) else { panic!() };
+		assert_eq!(&s[span], "5x");
+	}
+
+	#[test]
+	fn surrounding_whitespace() {
+		assert_eq!(Version::from_str(" 2.33.5.14 ").unwrap(), Version::new(2, 33, 5, 14));
+		assert_eq!(Version::from_str("\t2.33.5.14\n").unwrap(), Version::new(2, 33, 5, 14));
+	}
+
+	#[test]
+	fn internal_whitespace() {
+		assert!(matches!(Version::from_str("2. 33.5.14"), Err(ParseVersionError::InvalidComponent { index: 1, .. })))
+	}
+
+	#[test]
+	fn invalid_component_span() {
+		let s = "2.3x.5.14";
+		let Err(ParseVersionError::InvalidComponent { index, span, .. }) = Version::from_str(s) else { panic!() };
+		assert_eq!(index, 1);
+		assert_eq!(span, 2..4);
+		assert_eq!(&s[span], "3x");
+	}
+
+	#[test]
+	fn invalid_component_span_trimmed() {
+		let s = "  2.33.5.1x ";
+		let Err(ParseVersionError::InvalidComponent { span, .. }) = Version::from_str(s) else { panic!() };
+		assert_eq!(&s[span], "1x");
+	}
+
+	#[test]
+	fn invalid_component_span_substring() {
+		let s = "v2.33.x.14";
+		let Err(ParseVersionError::InvalidComponent { span, .. }) = Version::parse_prefixed(s, "v") else { panic!() };
+		assert_eq!(&s[span], "x");
+
+		let s = "wt_update_2.33.x.14";
+		let Err(ParseVersionError::InvalidComponent { span, .. }) = Version::parse_suffix(s) else { panic!() };
+		assert_eq!(&s[span], "x");
+	}
+
+	#[test]
+	fn invalid_component_span_optional() {
+		let s = "  2.3x.5.14";
+		let Err(ParseVersionError::InvalidComponent { span, .. }) = Version::parse_optional(s) else { panic!() };
+		assert_eq!(span, 4..6);
+	}
+
+	#[test]
+	fn invalid_component_span_git_describe() {
+		let s = " v2.3x.5.14-5-gabc";
+		let Err(ParseVersionError::InvalidComponent { span, .. }) = Version::parse_git_describe(s) else { panic!() };
+		assert_eq!(span, 4..6);
+		assert_eq!(&s[span], "3x");
+	}
+
+	#[test]
+	#[should_panic]
+	fn empty_str() {
+		Version::from_str("").unwrap();
 	}
 
 	#[test]
@@ -110,6 +1452,881 @@ mod tests {
 		Version::from_str("2.33.5..").unwrap();
 	}
 
+	#[test]
+	fn empty_str_error() {
+		assert_eq!(Version::from_str(""), Err(ParseVersionError::Empty));
+	}
+
+	#[test]
+	fn short_str_error() {
+		assert_eq!(Version::from_str("2.33.5"), Err(ParseVersionError::TooFewComponents { found: 3 }));
+	}
+
+	#[test]
+	fn long_str_error() {
+		assert_eq!(Version::from_str("2.33.5..abc"), Err(ParseVersionError::TooManyComponents { found: 5 }));
+	}
+
+	#[test]
+	fn invalid_component_error() {
+		assert!(matches!(
+			Version::from_str("2.33.x.14"),
+			Err(ParseVersionError::InvalidComponent { index: 2, .. })
+		));
+	}
+
+	#[test]
+	fn is_valid_full() {
+		assert!(Version::is_valid("2.33.5.14"))
+	}
+
+	#[test]
+	fn is_valid_too_short() {
+		assert!(!Version::is_valid("2.33.5"))
+	}
+
+	#[test]
+	fn is_valid_too_long() {
+		assert!(!Version::is_valid("2.33.5.14.0"))
+	}
+
+	#[test]
+	fn is_valid_invalid_component() {
+		assert!(!Version::is_valid("2.33.5.x"))
+	}
+
+	#[test]
+	fn is_valid_whitespace() {
+		assert!(Version::is_valid(" 2.33.5.14\n"))
+	}
+
+	#[test]
+	fn lenient_three_components() {
+		assert_eq!(Version::parse_lenient("33.5.14").unwrap(), Version::new(2, 33, 5, 14))
+	}
+
+	#[test]
+	fn lenient_four_components() {
+		assert_eq!(Version::parse_lenient("2.33.5.14").unwrap(), Version::new(2, 33, 5, 14))
+	}
+
+	#[test]
+	fn lenient_too_short() {
+		assert_eq!(Version::parse_lenient("33.5"), Err(ParseVersionError::TooFewComponents { found: 2 }))
+	}
+
+	#[test]
+	fn omit_global() {
+		assert_eq!(Version::parse_omit_global("33.5.14"), Ok(Version::new(2, 33, 5, 14)));
+		assert_eq!(Version::parse_omit_global("2.33.5.14"), Ok(Version::new(2, 33, 5, 14)));
+		assert_eq!(Version::parse_omit_global("2.33"), Err(ParseVersionError::TooFewComponents { found: 2 }));
+		assert_eq!(Version::parse_omit_global("2.33.5.14.1"), Err(ParseVersionError::TooManyComponents { found: 5 }));
+	}
+
+	#[test]
+	fn strict_rejects_three_components() {
+		assert!(Version::from_str("33.5.14").is_err())
+	}
+
+	#[test]
+	fn bump_resets_lower() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v.bump_patch(), Version::new(2, 33, 5, 15));
+		assert_eq!(v.bump_minor(), Version::new(2, 33, 6, 0));
+		assert_eq!(v.bump_major(), Version::new(2, 34, 0, 0));
+		assert_eq!(v.bump_global(), Version::new(3, 0, 0, 0));
+	}
+
+	#[test]
+	fn bump_saturates() {
+		let v = Version::new(u16::MAX, u16::MAX, u16::MAX, u16::MAX);
+		assert_eq!(v.bump_patch(), v);
+		assert_eq!(v.bump_minor(), v);
+		assert_eq!(v.bump_major(), v);
+		assert_eq!(v.bump_global(), v);
+		assert_eq!(Version::new(2, 33, u16::MAX, 14).bump_minor(), Version::new(2, 33, u16::MAX, 14));
+	}
+
+	#[test]
+	fn checked_add_patch() {
+		assert_eq!(Version::new(2, 33, 5, 14).checked_add_patch(5), Some(Version::new(2, 33, 5, 19)));
+		assert_eq!(Version::new(2, 33, 5, u16::MAX - 1).checked_add_patch(1), Some(Version::new(2, 33, 5, u16::MAX)));
+		assert_eq!(Version::new(2, 33, 5, u16::MAX - 1).checked_add_patch(2), None);
+	}
+
+	#[test]
+	fn bump_level() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v.bump(VersionLevel::Global), Version::new(3, 0, 0, 0));
+		assert_eq!(v.bump(VersionLevel::Major), Version::new(2, 34, 0, 0));
+		assert_eq!(v.bump(VersionLevel::Minor), Version::new(2, 33, 6, 0));
+		assert_eq!(v.bump(VersionLevel::Patch), Version::new(2, 33, 5, 15));
+		assert_eq!(Version::MAX.bump(VersionLevel::Minor), Version::MAX);
+	}
+
+	#[test]
+	fn checked_bump_patch() {
+		assert_eq!(Version::new(2, 33, 5, u16::MAX).checked_bump_patch(), None);
+		assert_eq!(Version::new(2, 33, 5, 14).checked_bump_patch(), Some(Version::new(2, 33, 5, 15)));
+	}
+
+	#[test]
+	fn checked_bump_resets_lower() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v.checked_bump_minor(), Some(Version::new(2, 33, 6, 0)));
+		assert_eq!(v.checked_bump_major(), Some(Version::new(2, 34, 0, 0)));
+		assert_eq!(v.checked_bump_global(), Some(Version::new(3, 0, 0, 0)));
+	}
+
+	#[test]
+	fn checked_bump_overflow() {
+		assert_eq!(Version::new(2, 33, u16::MAX, 14).checked_bump_minor(), None);
+		assert_eq!(Version::new(2, u16::MAX, 5, 14).checked_bump_major(), None);
+		assert_eq!(Version::new(u16::MAX, 33, 5, 14).checked_bump_global(), None);
+	}
+
+	#[test]
+	fn min_max() {
+		assert!(Version::MIN < Version::MAX);
+		assert_eq!(Version::MIN, Version::new(0, 0, 0, 0));
+		assert_eq!(Version::MAX, Version::new(u16::MAX, u16::MAX, u16::MAX, u16::MAX));
+	}
+
+	#[test]
+	fn default_impl() {
+		assert_eq!(Version::default(), Version::new(2, 0, 0, 0))
+	}
+
+	#[test]
+	fn eq_str() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v, "2.33.5.14");
+		assert_eq!("2.33.5.14", v);
+		assert!(v == *"2.33.5.14");
+		assert!(*"2.33.5.14" == v);
+	}
+
+	#[test]
+	fn ne_garbage_str() {
+		let v = Version::new(2, 33, 5, 14);
+		assert!(v != "garbage");
+		assert!("garbage" != v);
+		assert!(v != "2.33.5.15");
+	}
+
+	#[test]
+	fn diff_levels() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v.diff(&Version::new(3, 33, 5, 14)), Some(VersionLevel::Global));
+		assert_eq!(v.diff(&Version::new(2, 34, 0, 0)), Some(VersionLevel::Major));
+		assert_eq!(v.diff(&Version::new(2, 33, 6, 0)), Some(VersionLevel::Minor));
+		assert_eq!(v.diff(&Version::new(2, 33, 5, 15)), Some(VersionLevel::Patch));
+	}
+
+	#[test]
+	fn diff_equal() {
+		assert_eq!(Version::new(2, 33, 5, 14).diff(&Version::new(2, 33, 5, 14)), None)
+	}
+
+	#[test]
+	fn delta_components() {
+		let from = Version::new(2, 33, 5, 14);
+		let to = Version::new(2, 33, 6, 2);
+		assert_eq!(from.delta_components(&to), [0, 0, 1, -12]);
+		assert_eq!(to.delta_components(&from), [0, 0, -1, 12]);
+		assert_eq!(Version::MIN.delta_components(&Version::MAX), [65535; 4]);
+	}
+
+	#[test]
+	fn cmp_verbose() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v.cmp_verbose(&Version::new(2, 33, 6, 0)), (Ordering::Less, Some(VersionLevel::Minor)));
+		assert_eq!(v.cmp_verbose(&Version::new(2, 32, 9, 0)), (Ordering::Greater, Some(VersionLevel::Major)));
+		assert_eq!(v.cmp_verbose(&v), (Ordering::Equal, None));
+	}
+
+	#[test]
+	fn truncate_to() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v.truncate_to(VersionLevel::Global), Version::new(2, 0, 0, 0));
+		assert_eq!(v.truncate_to(VersionLevel::Major), Version::new(2, 33, 0, 0));
+		assert_eq!(v.truncate_to(VersionLevel::Minor), Version::new(2, 33, 5, 0));
+		assert_eq!(v.truncate_to(VersionLevel::Patch), v);
+	}
+
+	#[test]
+	fn newer_older_than_str() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v.is_newer_than_str("2.33.5.13"), Ok(true));
+		assert_eq!(v.is_older_than_str("2.33.5.13"), Ok(false));
+		assert_eq!(v.is_newer_than_str("2.34.0.0"), Ok(false));
+		assert_eq!(v.is_older_than_str("2.34.0.0"), Ok(true));
+		assert_eq!(v.is_newer_than_str("2.33.5.14"), Ok(false));
+		assert_eq!(v.is_older_than_str("2.33.5.14"), Ok(false));
+	}
+
+	#[test]
+	fn newer_older_than_str_malformed() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v.is_newer_than_str("2.33"), Err(ParseVersionError::TooFewComponents { found: 2 }));
+		assert_eq!(v.is_older_than_str(""), Err(ParseVersionError::Empty));
+	}
+
+	#[test]
+	fn same_minor() {
+		assert!(Version::new(2, 33, 5, 14).same_minor(&Version::new(2, 33, 5, 99)));
+		assert!(!Version::new(2, 33, 5, 14).same_minor(&Version::new(2, 33, 6, 0)));
+	}
+
+	#[test]
+	fn same_major() {
+		assert!(Version::new(2, 33, 5, 14).same_major(&Version::new(2, 33, 6, 0)));
+		assert!(!Version::new(2, 33, 5, 14).same_major(&Version::new(2, 34, 5, 14)));
+	}
+
+	#[test]
+	fn major_line() {
+		let (start, end) = Version::new(2, 33, 5, 14).major_line();
+		assert_eq!(start, Version::new(2, 33, 0, 0));
+		assert_eq!(end, Version::new(2, 34, 0, 0));
+	}
+
+	#[test]
+	fn major_line_saturates() {
+		let (start, end) = Version::new(2, u16::MAX, 5, 14).major_line();
+		assert_eq!(start, Version::new(2, u16::MAX, 0, 0));
+		assert_eq!(end, Version::new(3, 0, 0, 0));
+		assert!(!Version::new(3, 0, 0, 0).within_exclusive(start..end));
+		assert!(Version::new(2, u16::MAX, u16::MAX, u16::MAX).within_exclusive(start..end));
+
+		let (start, end) = Version::MAX.major_line();
+		assert_eq!(start, Version::new(u16::MAX, u16::MAX, 0, 0));
+		assert_eq!(end, Version::MAX);
+	}
+
+	#[test]
+	fn contains_in_line() {
+		let v = Version::new(2, 33, 5, 14);
+		assert!(v.contains_in_line(&Version::new(2, 33, 0, 0)));
+		assert!(v.contains_in_line(&Version::new(2, 33, u16::MAX, u16::MAX)));
+		assert!(!v.contains_in_line(&Version::new(2, 34, 0, 0)));
+		assert!(!v.contains_in_line(&Version::new(2, 32, 9, 9)));
+		assert!(!Version::new(2, u16::MAX, 0, 0).contains_in_line(&Version::new(3, 0, 0, 0)));
+	}
+
+	#[test]
+	fn pattern_full_wildcard() {
+		assert!(Version::new(2, 33, 5, 14).matches_pattern("*.*.*.*").unwrap())
+	}
+
+	#[test]
+	fn pattern_partial_wildcard() {
+		let v = Version::new(2, 33, 5, 14);
+		assert!(v.matches_pattern("2.33.*.*").unwrap());
+		assert!(v.matches_pattern("2.*.*.*").unwrap());
+		assert!(v.matches_pattern("2.*.5.*").unwrap());
+		assert!(v.matches_pattern("2.33.5.14").unwrap());
+	}
+
+	#[test]
+	fn pattern_mismatch() {
+		let v = Version::new(2, 33, 5, 14);
+		assert!(!v.matches_pattern("2.34.*.*").unwrap());
+		assert!(!v.matches_pattern("2.*.6.*").unwrap());
+	}
+
+	#[test]
+	fn pattern_invalid() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v.matches_pattern("2.*.*"), Err(ParseVersionError::TooFewComponents { found: 3 }));
+		assert!(matches!(v.matches_pattern("2.x.*.*"), Err(ParseVersionError::InvalidComponent { index: 1, .. })));
+	}
+
+	#[test]
+	fn distance_symmetric() {
+		let a = Version::new(2, 33, 5, 14);
+		let b = Version::new(2, 34, 0, 0);
+		assert_eq!(a.distance(&b), b.distance(&a));
+		assert_eq!(a.distance(&Version::new(2, 33, 5, 16)), 2);
+	}
+
+	#[test]
+	fn distance_equal() {
+		assert_eq!(Version::new(2, 33, 5, 14).distance(&Version::new(2, 33, 5, 14)), 0)
+	}
+
+	#[test]
+	fn clamp_to() {
+		let min = Version::new(2, 30, 0, 0);
+		let max = Version::new(2, 33, 0, 0);
+		assert_eq!(Version::new(2, 29, 5, 14).clamp_to(min, max), min);
+		assert_eq!(Version::new(2, 31, 5, 14).clamp_to(min, max), Version::new(2, 31, 5, 14));
+		assert_eq!(Version::new(2, 33, 5, 14).clamp_to(min, max), max);
+	}
+
+	#[test]
+	fn within() {
+		let (min, max) = (Version::new(2, 30, 0, 0), Version::new(2, 33, 0, 0));
+		assert!(Version::new(2, 31, 5, 14).within(min..=max));
+		assert!(min.within(min..=max));
+		assert!(max.within(min..=max));
+		assert!(!Version::new(2, 29, 5, 14).within(min..=max));
+		assert!(!Version::new(2, 33, 0, 1).within(min..=max));
+	}
+
+	#[test]
+	fn within_exclusive() {
+		let (min, max) = (Version::new(2, 30, 0, 0), Version::new(2, 33, 0, 0));
+		assert!(Version::new(2, 31, 5, 14).within_exclusive(min..max));
+		assert!(min.within_exclusive(min..max));
+		assert!(!max.within_exclusive(min..max));
+		assert!(Version::new(2, 32, u16::MAX, u16::MAX).within_exclusive(min..max));
+		assert!(!Version::new(2, 29, 5, 14).within_exclusive(min..max));
+	}
+
+	#[test]
+	fn exact_leading_zero() {
+		assert_eq!(Version::from_str_exact("2.033.5.14"), Err(ParseVersionError::LeadingZero { index: 1 }));
+		assert_eq!(Version::from_str_exact("2.33.5.00"), Err(ParseVersionError::LeadingZero { index: 3 }));
+	}
+
+	#[test]
+	fn exact_sign() {
+		assert_eq!(Version::from_str_exact("2.+033.5.14"), Err(ParseVersionError::NonDigit { index: 1 }));
+		assert_eq!(Version::from_str_exact("2.+33.5.14"), Err(ParseVersionError::NonDigit { index: 1 }));
+		assert!(Version::from_str_exact("2.33.5.1x").is_err());
+	}
+
+	#[test]
+	fn exact_zero_component() {
+		assert_eq!(Version::from_str_exact("2.0.5.14").unwrap(), Version::new(2, 0, 5, 14))
+	}
+
+	#[test]
+	fn padded() {
+		assert_eq!(Version::parse_padded("2").unwrap(), Version::new(2, 0, 0, 0));
+		assert_eq!(Version::parse_padded("2.33").unwrap(), Version::new(2, 33, 0, 0));
+		assert_eq!(Version::parse_padded("2.33.5").unwrap(), Version::new(2, 33, 5, 0));
+		assert_eq!(Version::parse_padded("2.33.5.14").unwrap(), Version::new(2, 33, 5, 14));
+	}
+
+	#[test]
+	fn padded_too_long() {
+		assert_eq!(Version::parse_padded("2.33.5.14.0"), Err(ParseVersionError::TooManyComponents { found: 5 }))
+	}
+
+	#[test]
+	fn radix_hex() {
+		assert_eq!(Version::parse_radix("2.21.5.e", 16).unwrap(), Version::new(2, 33, 5, 14));
+		assert_eq!(Version::parse_radix("2.21.5.E", 16).unwrap(), Version::new(2, 33, 5, 14));
+	}
+
+	#[test]
+	fn radix_out_of_range_digit() {
+		assert!(matches!(Version::parse_radix("2.21.5.g", 16), Err(ParseVersionError::InvalidComponent { index: 3, .. })));
+		assert!(matches!(Version::parse_radix("2.33.5.14", 2), Err(ParseVersionError::InvalidComponent { index: 0, .. })));
+	}
+
+	#[test]
+	fn separator_dash() {
+		assert_eq!(Version::parse_with_separator("2-33-5-14", '-').unwrap(), Version::new(2, 33, 5, 14))
+	}
+
+	#[test]
+	fn separator_underscore() {
+		assert_eq!(Version::parse_with_separator("2_33_5_14", '_').unwrap(), Version::new(2, 33, 5, 14))
+	}
+
+	#[test]
+	fn separator_mismatch() {
+		assert_eq!(Version::parse_with_separator("2.33.5.14", '-').unwrap_err(), ParseVersionError::TooFewComponents { found: 1 })
+	}
+
+	#[test]
+	fn patch_range() {
+		let range = Version::patch_range(Version::new(2, 33, 5, 14), Version::new(2, 33, 5, 17)).collect::<Vec<_>>();
+		assert_eq!(range, [
+			Version::new(2, 33, 5, 14),
+			Version::new(2, 33, 5, 15),
+			Version::new(2, 33, 5, 16),
+			Version::new(2, 33, 5, 17),
+		]);
+	}
+
+	#[test]
+	fn patch_range_rollover() {
+		let range = Version::patch_range(Version::new(2, 33, 5, u16::MAX), Version::new(2, 33, 6, 0)).collect::<Vec<_>>();
+		assert_eq!(range, [Version::new(2, 33, 5, u16::MAX), Version::new(2, 33, 6, 0)]);
+	}
+
+	#[test]
+	fn patch_range_reversed() {
+		assert_eq!(Version::patch_range(Version::new(2, 33, 5, 17), Version::new(2, 33, 5, 14)).count(), 0)
+	}
+
+	#[test]
+	fn flexible() {
+		assert_eq!(Version::parse_flexible("2.33.5.14").unwrap(), Version::new(2, 33, 5, 14));
+		assert_eq!(Version::parse_flexible("2-33-5-14").unwrap(), Version::new(2, 33, 5, 14));
+		assert_eq!(Version::parse_flexible("2_33_5_14").unwrap(), Version::new(2, 33, 5, 14));
+	}
+
+	#[test]
+	fn flexible_no_separator() {
+		assert_eq!(Version::parse_flexible("2 33 5 14"), Err(ParseVersionError::AmbiguousSeparator));
+		assert_eq!(Version::parse_flexible("2.33-5_14"), Err(ParseVersionError::AmbiguousSeparator));
+	}
+
+	#[test]
+	fn prefixed() {
+		assert_eq!(Version::parse_prefixed("v2.33.5.14", "v").unwrap(), Version::new(2, 33, 5, 14));
+		assert_eq!(Version::parse_prefixed("War Thunder 2.33.5.14", "War Thunder ").unwrap(), Version::new(2, 33, 5, 14));
+	}
+
+	#[test]
+	fn prefixed_missing() {
+		assert_eq!(Version::parse_prefixed("2.33.5.14", "v"), Err(ParseVersionError::MissingPrefix { prefix: "v".into() }))
+	}
+
+	#[test]
+	fn git_describe() {
+		assert_eq!(Version::parse_git_describe("v2.33.5.14-5-g1a2b3c").unwrap(), Version::new(2, 33, 5, 14));
+		assert_eq!(Version::parse_git_describe("2.33.5.14").unwrap(), Version::new(2, 33, 5, 14));
+		assert_eq!(Version::parse_git_describe("v2.33.5.14").unwrap(), Version::new(2, 33, 5, 14));
+	}
+
+	#[test]
+	fn git_describe_malformed() {
+		assert_eq!(Version::parse_git_describe("v2.33-5-g1a2b3c"), Err(ParseVersionError::TooFewComponents { found: 2 }));
+		assert_eq!(Version::parse_git_describe("g1a2b3c"), Err(ParseVersionError::TooFewComponents { found: 1 }));
+	}
+
+	#[test]
+	fn suffix() {
+		assert_eq!(Version::parse_suffix("wt_update_2.33.5.14").unwrap(), Version::new(2, 33, 5, 14));
+		assert_eq!(Version::parse_suffix("build 2.33.5.14").unwrap(), Version::new(2, 33, 5, 14));
+		assert_eq!(Version::parse_suffix("2.33.5.14").unwrap(), Version::new(2, 33, 5, 14));
+	}
+
+	#[test]
+	fn suffix_not_a_version() {
+		assert_eq!(Version::parse_suffix("wt_update_latest"), Err(ParseVersionError::TooFewComponents { found: 1 }))
+	}
+
+	#[test]
+	fn counted() {
+		assert_eq!(Version::parse_counted("2.33.5"), Err((3, ParseVersionError::TooFewComponents { found: 3 })));
+		assert_eq!(Version::parse_counted("2.33.5.14.0"), Err((5, ParseVersionError::TooManyComponents { found: 5 })));
+		assert_eq!(Version::parse_counted(""), Err((0, ParseVersionError::Empty)));
+		assert_eq!(Version::parse_counted("2.33.5.14"), Ok(Version::new(2, 33, 5, 14)));
+	}
+
+	#[test]
+	fn bounded() {
+		let (min, max) = (Version::new(2, 30, 0, 0), Version::new(2, 34, 0, 0));
+		assert_eq!(Version::parse_bounded("2.33.5.14", min, max), Ok(Version::new(2, 33, 5, 14)));
+		assert_eq!(Version::parse_bounded("2.34.0.0", min, max), Ok(max));
+	}
+
+	#[test]
+	fn bounded_out_of_range() {
+		let (min, max) = (Version::new(2, 30, 0, 0), Version::new(2, 34, 0, 0));
+		let below = Version::new(2, 29, 0, 0);
+		let above = Version::new(2, 34, 0, 1);
+		assert_eq!(Version::parse_bounded("2.29.0.0", min, max), Err(ParseVersionError::OutOfRange { value: below, min, max }));
+		assert_eq!(Version::parse_bounded("2.34.0.1", min, max), Err(ParseVersionError::OutOfRange { value: above, min, max }));
+	}
+
+	#[test]
+	fn optional_unset() {
+		for s in ["", "none", "None", "ANY", "any"] {
+			assert_eq!(Version::parse_optional(s), Ok(None), "{s:?}");
+		}
+	}
+
+	#[test]
+	fn optional_set() {
+		assert_eq!(Version::parse_optional("2.33.5.14"), Ok(Some(Version::new(2, 33, 5, 14))))
+	}
+
+	#[test]
+	fn optional_invalid() {
+		assert_eq!(Version::parse_optional("2.33.5"), Err(ParseVersionError::TooFewComponents { found: 3 }))
+	}
+
+	#[test]
+	fn best_effort() {
+		assert_eq!(Version::parse_best_effort("2.33.5.14"), (Version::new(2, 33, 5, 14), true));
+		assert_eq!(Version::parse_best_effort("0.0.0.0"), (Version::MIN, true));
+	}
+
+	#[test]
+	fn best_effort_garbage() {
+		assert_eq!(Version::parse_best_effort("garbage"), (Version::MIN, false));
+		assert_eq!(Version::parse_best_effort(""), (Version::MIN, false));
+	}
+
+	#[test]
+	fn lines() {
+		let input = "2.33.5.14\n2.33.6.0\n\n2.34.0.0\n";
+		assert_eq!(Version::parse_lines(input).unwrap(), [
+			Version::new(2, 33, 5, 14),
+			Version::new(2, 33, 6, 0),
+			Version::new(2, 34, 0, 0),
+		]);
+	}
+
+	#[test]
+	fn lines_error() {
+		let input = "2.33.5.14\n\n2.33.6\n2.34.0.0";
+		assert_eq!(Version::parse_lines(input), Err((3, ParseVersionError::TooFewComponents { found: 3 })));
+	}
+
+	#[test]
+	fn parse_all() {
+		let input = "2.33.5.14\n\n2.33.6\ngarbage\n2.34.0.0\n";
+		let (versions, errors) = Version::parse_all(input);
+		assert_eq!(versions, [Version::new(2, 33, 5, 14), Version::new(2, 34, 0, 0)]);
+		assert_eq!(errors, [
+			(3, ParseVersionError::TooFewComponents { found: 3 }),
+			(4, ParseVersionError::TooFewComponents { found: 1 }),
+		]);
+	}
+
+	fn hash_of(v: &Version) -> u64 {
+		let mut hasher = std::hash::DefaultHasher::new();
+		v.hash(&mut hasher);
+		hasher.finish()
+	}
+
+	#[test]
+	fn hash_set_lookup() {
+		use std::collections::HashSet;
+		let versions = [
+			Version::new(2, 33, 5, 14),
+			Version::new(2, 33, 6, 0),
+			Version::new(2, 34, 0, 0),
+			Version::MIN,
+			Version::MAX,
+		];
+		let set = versions.into_iter().collect::<HashSet<_>>();
+		assert_eq!(set.len(), versions.len());
+		for v in versions {
+			assert!(set.contains(&Version::from_u64(v.to_u64())));
+		}
+		assert!(!set.contains(&Version::new(2, 33, 5, 15)));
+	}
+
+	#[test]
+	fn stable_id_is_pinned() {
+		assert_eq!(Version::new(2, 33, 5, 14).stable_id(), 0x0002_0021_0005_000E);
+		assert_eq!(Version::MAX.stable_id(), u64::MAX);
+	}
+
+	#[test]
+	fn hash_is_packed() {
+		let v = Version::new(2, 33, 5, 14);
+		let mut hasher = std::hash::DefaultHasher::new();
+		v.to_u64().hash(&mut hasher);
+		assert_eq!(hash_of(&v), hasher.finish());
+		assert_ne!(hash_of(&v), hash_of(&Version::new(2, 33, 5, 15)));
+	}
+
+	#[test]
+	fn hash_consistent_with_eq() {
+		let a = Version::new(2, 33, 5, 14);
+		let b = Version::from_str("2.33.5.14").unwrap();
+		assert_eq!(a, b);
+		assert_eq!(hash_of(&a), hash_of(&b));
+	}
+
+	#[test]
+	fn major_release() {
+		assert!(Version::new(2, 33, 0, 0).is_major_release());
+		assert!(!Version::new(2, 33, 0, 0).is_hotfix());
+	}
+
+	#[test]
+	fn hotfix() {
+		assert!(Version::new(2, 33, 5, 14).is_hotfix());
+		assert!(!Version::new(2, 33, 5, 14).is_major_release());
+	}
+
+	#[test]
+	fn successor() {
+		assert_eq!(Version::new(2, 33, 5, 14).successor(), Some(Version::new(2, 33, 5, 15)));
+		assert_eq!(Version::new(2, 33, 5, u16::MAX).successor(), Some(Version::new(2, 33, 6, 0)));
+		assert_eq!(Version::MAX.successor(), None);
+	}
+
+	#[test]
+	fn predecessor() {
+		assert_eq!(Version::new(2, 33, 5, 14).predecessor(), Some(Version::new(2, 33, 5, 13)));
+		assert_eq!(Version::new(2, 33, 6, 0).predecessor(), Some(Version::new(2, 33, 5, u16::MAX)));
+		assert_eq!(Version::MIN.predecessor(), None);
+	}
+
+	#[test]
+	fn nearest() {
+		let candidates = [Version::new(2, 30, 0, 0), Version::new(2, 33, 5, 10), Version::new(2, 33, 6, 0)];
+		assert_eq!(Version::new(2, 33, 5, 14).nearest(&candidates), Some(&Version::new(2, 33, 5, 10)));
+		assert_eq!(Version::new(2, 33, 5, u16::MAX).nearest(&candidates), Some(&Version::new(2, 33, 6, 0)));
+	}
+
+	#[test]
+	fn nearest_tie() {
+		let candidates = [Version::new(2, 33, 5, 16), Version::new(2, 33, 5, 12)];
+		assert_eq!(Version::new(2, 33, 5, 14).nearest(&candidates), Some(&Version::new(2, 33, 5, 12)));
+	}
+
+	#[test]
+	fn nearest_empty() {
+		assert_eq!(Version::new(2, 33, 5, 14).nearest(&[]), None)
+	}
+
+	#[test]
+	fn const_comparison() {
+		const {
+			assert!(Version::new(2, 32, 0, 0).lt(&Version::new(2, 33, 0, 0)));
+			assert!(Version::new(2, 33, 0, 0).gt(&Version::new(2, 32, 0, 0)));
+			assert!(!Version::new(2, 33, 0, 0).lt(&Version::new(2, 33, 0, 0)));
+		}
+	}
+
+	#[cfg(feature = "rkyv")]
+	#[test]
+	fn rkyv_access() {
+		let v = Version::new(2, 33, 5, 14);
+		let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&v).unwrap();
+		let archived = rkyv::access::<ArchivedVersion, rkyv::rancor::Error>(&bytes).unwrap();
+		assert_eq!(archived.global, 2);
+		assert_eq!(archived.major, 33);
+		assert_eq!(archived.minor, 5);
+		assert_eq!(archived.patch, 14);
+		assert_eq!(rkyv::deserialize::<Version, rkyv::rancor::Error>(archived).unwrap(), v);
+	}
+
+	#[test]
+	fn max_min_of() {
+		let versions = [Version::new(2, 33, 5, 14), Version::new(2, 34, 0, 0), Version::new(2, 30, 1, 0), Version::new(2, 33, 6, 0)];
+		assert_eq!(Version::max_of(versions), Some(Version::new(2, 34, 0, 0)));
+		assert_eq!(Version::min_of(versions), Some(Version::new(2, 30, 1, 0)));
+	}
+
+	#[test]
+	fn max_min_of_empty() {
+		assert_eq!(Version::max_of([]), None);
+		assert_eq!(Version::min_of([]), None);
+	}
+
+	#[test]
+	fn eq_u64() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(v, 0x0002_0021_0005_000E_u64);
+		assert_eq!(0x0002_0021_0005_000E_u64, v);
+		assert_ne!(v, 0x0002_0021_0005_000F_u64);
+	}
+
+	#[test]
+	fn ord_u64() {
+		let v = Version::new(2, 33, 5, 14);
+		assert!(v > 0x0002_0021_0005_000D_u64);
+		assert!(v < 0x0002_0021_0005_000F_u64);
+		assert!(0x0002_0021_0005_000D_u64 < v);
+		assert!(0x0002_0021_0005_000F_u64 > v);
+	}
+
+	#[test]
+	fn sort_dedup() {
+		let mut versions = vec![
+			Version::new(2, 34, 0, 0),
+			Version::new(2, 33, 5, 14),
+			Version::new(2, 34, 0, 0),
+			Version::new(2, 30, 0, 0),
+			Version::new(2, 33, 5, 14),
+		];
+		Version::sort_dedup(&mut versions);
+		assert_eq!(versions, [Version::new(2, 30, 0, 0), Version::new(2, 33, 5, 14), Version::new(2, 34, 0, 0)]);
+	}
+
+	fn lookup_table() -> BTreeMap<Version, &'static str> {
+		BTreeMap::from([
+			(Version::new(2, 30, 0, 0), "a"),
+			(Version::new(2, 33, 0, 0), "b"),
+			(Version::new(2, 33, 5, 14), "c"),
+		])
+	}
+
+	#[test]
+	fn floor_in() {
+		let map = lookup_table();
+		assert_eq!(Version::floor_in(&map, &Version::new(2, 33, 5, 0)), Some((&Version::new(2, 33, 0, 0), &"b")));
+		assert_eq!(Version::floor_in(&map, &Version::new(2, 33, 5, 14)), Some((&Version::new(2, 33, 5, 14), &"c")));
+		assert_eq!(Version::floor_in(&map, &Version::new(2, 29, 0, 0)), None);
+	}
+
+	#[test]
+	fn ceil_in() {
+		let map = lookup_table();
+		assert_eq!(Version::ceil_in(&map, &Version::new(2, 33, 5, 0)), Some((&Version::new(2, 33, 5, 14), &"c")));
+		assert_eq!(Version::ceil_in(&map, &Version::new(2, 30, 0, 0)), Some((&Version::new(2, 30, 0, 0), &"a")));
+		assert_eq!(Version::ceil_in(&map, &Version::new(2, 34, 0, 0)), None);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn parse_reader() {
+		let input = std::io::Cursor::new(b"2.33.5.14\n\n2.33.6\n2.34.0.0\n");
+		assert_eq!(Version::parse_reader(input).collect::<Vec<_>>(), [
+			Ok(Version::new(2, 33, 5, 14)),
+			Err((3, ParseVersionError::TooFewComponents { found: 3 })),
+			Ok(Version::new(2, 34, 0, 0)),
+		]);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn parse_reader_invalid_utf8() {
+		let input = std::io::Cursor::new(b"2.33.5.14\n\xFF\n");
+		assert_eq!(
+			Version::parse_reader(input).nth(1),
+			Some(Err((2, ParseVersionError::Io { kind: std::io::ErrorKind::InvalidData })))
+		);
+	}
+
+	#[test]
+	fn parse_iter() {
+		assert_eq!(
+			Version::parse_iter("old 2.33.5.14 new 2.34.0.0 done").collect::<Vec<_>>(),
+			[Version::new(2, 33, 5, 14), Version::new(2, 34, 0, 0)]
+		);
+	}
+
+	#[test]
+	fn within_patch() {
+		let a = Version::new(2, 33, 5, 14);
+		let b = Version::new(2, 33, 5, 16);
+		assert!(a.within_patch(&b, 2));
+		assert!(b.within_patch(&a, 2));
+		assert!(!a.within_patch(&b, 1));
+	}
+
+	#[test]
+	fn within_patch_different_minor() {
+		assert!(!Version::new(2, 33, 5, 14).within_patch(&Version::new(2, 33, 6, 14), u16::MAX))
+	}
+
+	#[test]
+	fn compatibility_exact() {
+		let v = Version::new(2, 33, 5, 14);
+		assert!(v.is_compatible_with(&Version::new(2, 33, 5, 14), CompatibilityPolicy::Exact));
+		assert!(!v.is_compatible_with(&Version::new(2, 33, 5, 15), CompatibilityPolicy::Exact));
+	}
+
+	#[test]
+	fn compatibility_same_minor() {
+		let v = Version::new(2, 33, 5, 14);
+		assert!(v.is_compatible_with(&Version::new(2, 33, 5, 99), CompatibilityPolicy::SameMinor));
+		assert!(!v.is_compatible_with(&Version::new(2, 33, 6, 14), CompatibilityPolicy::SameMinor));
+	}
+
+	#[test]
+	fn compatibility_same_major() {
+		let v = Version::new(2, 33, 5, 14);
+		assert!(v.is_compatible_with(&Version::new(2, 33, 9, 0), CompatibilityPolicy::SameMajor));
+		assert!(!v.is_compatible_with(&Version::new(2, 34, 5, 14), CompatibilityPolicy::SameMajor));
+	}
+
+	#[test]
+	fn compatibility_same_global() {
+		let v = Version::new(2, 33, 5, 14);
+		assert!(v.is_compatible_with(&Version::new(2, 40, 0, 0), CompatibilityPolicy::SameGlobal));
+		assert!(!v.is_compatible_with(&Version::new(3, 33, 5, 14), CompatibilityPolicy::SameGlobal));
+	}
+
+	#[test]
+	fn cmp_matches_field_by_field() {
+		let field_by_field = |a: &Version, b: &Version| {
+			a.global.cmp(&b.global)
+				.then_with(|| a.major.cmp(&b.major))
+				.then_with(|| a.minor.cmp(&b.minor))
+				.then_with(|| a.patch.cmp(&b.patch))
+		};
+		let mut state = 0x2_0021_0005_000E_u64;
+		let mut next = move || {
+			state ^= state << 13;
+			state ^= state >> 7;
+			state ^= state << 17;
+			Version::from_u64(state)
+		};
+		for _ in 0..100_000 {
+			let (a, b) = (next(), next());
+			// Also compare versions sharing global and major, as real ones do
+			let shared = Version::new(a.global, a.major, b.minor, b.patch);
+			assert_eq!(a.cmp(&b), field_by_field(&a, &b), "{a} vs {b}");
+			assert_eq!(a.cmp(&shared), field_by_field(&a, &shared), "{a} vs {shared}");
+		}
+	}
+
+	#[test]
+	fn cmp_matches_tuple_across_carries() {
+		let tuple = |v: &Version| (v.global, v.major, v.minor, v.patch);
+		let pairs = [
+			(Version::new(2, 0, 65535, 65535), Version::new(2, 1, 0, 0)),
+			(Version::new(2, 33, 5, 65535), Version::new(2, 33, 6, 0)),
+			(Version::new(1, 65535, 65535, 65535), Version::new(2, 0, 0, 0)),
+			(Version::new(2, 33, 65535, 0), Version::new(2, 34, 0, 65535)),
+			(Version::new(2, 33, 5, 14), Version::new(2, 33, 5, 15)),
+			(Version::new(2, 33, 5, 14), Version::new(2, 33, 5, 14)),
+		];
+		for (a, b) in pairs {
+			for level in VersionLevel::iter() {
+				// Also compare with the less significant components zeroed
+				let (a, b) = (a.truncate_to(level), b.truncate_to(level));
+				assert_eq!(a.cmp(&b), tuple(&a).cmp(&tuple(&b)), "{a} vs {b}");
+				assert_eq!(b.cmp(&a), tuple(&b).cmp(&tuple(&a)), "{b} vs {a}");
+			}
+		}
+	}
+
+	#[test]
+	fn add_u64() {
+		assert_eq!(Version::new(2, 33, 5, 14) + 1, Version::new(2, 33, 5, 15));
+		assert_eq!(Version::new(2, 33, 5, u16::MAX) + 1, Version::new(2, 33, 6, 0));
+		assert_eq!(Version::new(u16::MAX, u16::MAX, u16::MAX, u16::MAX - 1) + 5, Version::MAX);
+	}
+
+	#[test]
+	fn sub_u64() {
+		assert_eq!(Version::new(2, 33, 5, 14) - 1, Version::new(2, 33, 5, 13));
+		assert_eq!(Version::new(2, 33, 6, 0) - 1, Version::new(2, 33, 5, u16::MAX));
+		assert_eq!(Version::new(0, 0, 0, 3) - 5, Version::MIN);
+	}
+
+	#[test]
+	fn total_order() {
+		let samples = BOUNDARIES.into_iter().flat_map(|a| BOUNDARIES.map(|b| Version::new(2, a, b, a))).collect::<Vec<_>>();
+		for a in &samples {
+			for b in &samples {
+				assert_eq!(a.partial_cmp(b), Some(a.cmp(b)), "{a} vs {b}");
+				assert_eq!(a.cmp(b), a.to_u64().cmp(&b.to_u64()), "{a} vs {b}");
+			}
+		}
+	}
+
+	#[test]
+	fn compare_strings() {
+		assert_eq!(super::compare_strings("2.33.5.14", "2.33.6.0"), Ok(Ordering::Less));
+		assert_eq!(super::compare_strings("2.33.5.14", "2.33.5.14"), Ok(Ordering::Equal));
+		assert_eq!(super::compare_strings("2.34.0.0", "2.33.6.0"), Ok(Ordering::Greater));
+	}
+
+	#[test]
+	fn compare_strings_malformed() {
+		assert_eq!(super::compare_strings("2.33.5", "2.33.6.0"), Err(ParseVersionError::TooFewComponents { found: 3 }));
+		assert_eq!(super::compare_strings("2.33.5.14", ""), Err(ParseVersionError::Empty));
+	}
+
 	#[test]
 	fn equal() {
 		assert_eq!(
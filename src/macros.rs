@@ -0,0 +1,81 @@
+use crate::Version;
+
+/// Builds a [`Version`] from a dotted literal at compile time
+///
+/// Malformed literals, such as ones with the wrong number of components, fail to compile
+///
+/// ```
+/// use wt_version::{version, Version};
+///
+/// const V: Version = version!(2.33.5.14);
+/// assert_eq!(V, Version::new(2, 33, 5, 14));
+/// ```
+#[macro_export]
+macro_rules! version {
+	($($t:tt)+) => {{
+		// `2.33.5.14` lexes as `2.33`, `.`, `5.14`, so the tokens are stitched back together and parsed in const
+		const VERSION: $crate::Version = $crate::__parse_literal(concat!($(stringify!($t)),+));
+		VERSION
+	}};
+}
+
+#[doc(hidden)]
+pub const fn __parse_literal(s: &str) -> Version {
+	let bytes = s.as_bytes();
+	let mut components = [0_u16; 4];
+	let mut index = 0;
+	let mut value = 0_u32;
+	let mut digits = 0;
+	let mut i = 0;
+	while i < bytes.len() {
+		match bytes[i] {
+			b @ b'0'..=b'9' => {
+				value = value * 10 + (b - b'0') as u32;
+				if value > u16::MAX as u32 {
+					panic!("version component does not fit in a u16");
+				}
+				digits += 1;
+			}
+			b'.' => {
+				if digits == 0 {
+					panic!("version literal has an empty component");
+				}
+				if index == 3 {
+					panic!("version literal has more than 4 components");
+				}
+				components[index] = value as u16;
+				index += 1;
+				value = 0;
+				digits = 0;
+			}
+			_ => panic!("version literal may only contain digits and dots"),
+		}
+		i += 1;
+	}
+	if digits == 0 {
+		panic!("version literal has an empty component");
+	}
+	if index != 3 {
+		panic!("version literal has fewer than 4 components");
+	}
+	components[3] = value as u16;
+	Version::new(components[0], components[1], components[2], components[3])
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::Version;
+
+	#[test]
+	fn literal() {
+		assert_eq!(version!(2.33.5.14), Version::new(2, 33, 5, 14));
+		assert_eq!(version!(2.0.0.0), Version::new(2, 0, 0, 0));
+		assert_eq!(version!(65535.65535.65535.65535), Version::MAX);
+	}
+
+	#[test]
+	fn const_literal() {
+		const V: Version = version!(2.33.5.14);
+		assert_eq!(V, Version::new(2, 33, 5, 14));
+	}
+}
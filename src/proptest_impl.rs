@@ -0,0 +1,37 @@
+use proptest::prelude::*;
+
+use crate::Version;
+
+impl Version {
+	/// Generates every component uniformly
+	pub fn arbitrary_strategy() -> impl Strategy<Value = Version> {
+		any::<[u16; 4]>().prop_map(Version::from)
+	}
+
+	/// Like [`Version::arbitrary_strategy`], but mostly generates a global of 2 like real versions have
+	pub fn biased_strategy() -> impl Strategy<Value = Version> {
+		let global = prop_oneof![9 => Just(2_u16), 1 => any::<u16>()];
+		(global, any::<[u16; 3]>()).prop_map(|(global, [major, minor, patch])| Version::new(global, major, minor, patch))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::str::FromStr;
+
+	use proptest::prelude::*;
+
+	use crate::Version;
+
+	proptest! {
+		#[test]
+		fn display_from_str_round_trip(v in Version::arbitrary_strategy()) {
+			prop_assert_eq!(Version::from_str(&v.to_string()), Ok(v));
+		}
+
+		#[test]
+		fn biased_display_from_str_round_trip(v in Version::biased_strategy()) {
+			prop_assert_eq!(Version::from_str(&v.to_string()), Ok(v));
+		}
+	}
+}
@@ -0,0 +1,121 @@
+use core::iter::FusedIterator;
+
+use crate::Version;
+
+/// Iterates every version between two bounds inclusively by walking the packed u64, see [`Version::range`]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct VersionRange {
+	front: u64,
+	back: u64,
+	exhausted: bool,
+}
+
+impl VersionRange {
+	/// Empty when `start > end`
+	pub const fn new(start: Version, end: Version) -> Self {
+		Self {
+			front: start.to_u64(),
+			back: end.to_u64(),
+			exhausted: start.to_u64() > end.to_u64(),
+		}
+	}
+}
+
+impl Iterator for VersionRange {
+	type Item = Version;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.exhausted {
+			return None;
+		}
+		let v = Version::from_u64(self.front);
+		if self.front == self.back {
+			self.exhausted = true;
+		} else {
+			self.front += 1;
+		}
+		Some(v)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl DoubleEndedIterator for VersionRange {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.exhausted {
+			return None;
+		}
+		let v = Version::from_u64(self.back);
+		if self.front == self.back {
+			self.exhausted = true;
+		} else {
+			self.back -= 1;
+		}
+		Some(v)
+	}
+}
+
+/// Saturates at `usize::MAX` for ranges wider than the platform can count
+impl ExactSizeIterator for VersionRange {
+	fn len(&self) -> usize {
+		if self.exhausted {
+			return 0;
+		}
+		usize::try_from(self.back - self.front).map_or(usize::MAX, |delta| delta.saturating_add(1))
+	}
+}
+
+impl FusedIterator for VersionRange {}
+
+#[cfg(test)]
+mod tests {
+	use crate::Version;
+
+	#[test]
+	fn forward() {
+		let range = Version::range(Version::new(2, 33, 5, 14), Version::new(2, 33, 5, 16));
+		assert_eq!(range.len(), 3);
+		assert_eq!(range.collect::<Vec<_>>(), [
+			Version::new(2, 33, 5, 14),
+			Version::new(2, 33, 5, 15),
+			Version::new(2, 33, 5, 16),
+		]);
+	}
+
+	#[test]
+	fn reverse() {
+		let range = Version::range(Version::new(2, 33, 5, u16::MAX), Version::new(2, 33, 6, 1));
+		assert_eq!(range.rev().collect::<Vec<_>>(), [
+			Version::new(2, 33, 6, 1),
+			Version::new(2, 33, 6, 0),
+			Version::new(2, 33, 5, u16::MAX),
+		]);
+	}
+
+	#[test]
+	fn both_ends() {
+		let mut range = Version::range(Version::new(2, 33, 5, 14), Version::new(2, 33, 5, 16));
+		assert_eq!(range.next(), Some(Version::new(2, 33, 5, 14)));
+		assert_eq!(range.next_back(), Some(Version::new(2, 33, 5, 16)));
+		assert_eq!(range.len(), 1);
+		assert_eq!(range.next_back(), Some(Version::new(2, 33, 5, 15)));
+		assert_eq!(range.next(), None);
+		assert_eq!(range.len(), 0);
+	}
+
+	#[test]
+	fn empty() {
+		let mut range = Version::range(Version::new(2, 33, 5, 16), Version::new(2, 33, 5, 14));
+		assert_eq!(range.len(), 0);
+		assert_eq!(range.next(), None);
+	}
+
+	#[test]
+	fn upper_bound() {
+		let range = Version::range(Version::MAX, Version::MAX);
+		assert_eq!(range.collect::<Vec<_>>(), [Version::MAX]);
+	}
+}
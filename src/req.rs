@@ -0,0 +1,190 @@
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+use crate::{span_of, ParseVersionError, Version};
+
+/// Comparison operator of a [`VersionReq`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Op {
+	/// `=`
+	Exact,
+	/// `>`
+	Greater,
+	/// `>=`
+	GreaterEq,
+	/// `<`
+	Less,
+	/// `<=`
+	LessEq,
+	/// `~`, at least the version but within the same minor line
+	Tilde,
+}
+
+impl Op {
+	const fn as_str(&self) -> &'static str {
+		match self {
+			Op::Exact => "=",
+			Op::Greater => ">",
+			Op::GreaterEq => ">=",
+			Op::Less => "<",
+			Op::LessEq => "<=",
+			Op::Tilde => "~",
+		}
+	}
+}
+
+/// A requirement such as `>=2.30.0.0` or `~2.33.0.0` that versions can be matched against
+///
+/// A requirement without an operator is treated as `=`
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct VersionReq {
+	op: Op,
+	version: Version,
+}
+
+impl VersionReq {
+	pub const fn new(op: Op, version: Version) -> Self {
+		Self { op, version }
+	}
+
+	pub const fn op(&self) -> Op {
+		self.op
+	}
+
+	pub const fn version(&self) -> Version {
+		self.version
+	}
+
+	pub fn matches(&self, v: &Version) -> bool {
+		match self.op {
+			Op::Exact => *v == self.version,
+			Op::Greater => *v > self.version,
+			Op::GreaterEq => *v >= self.version,
+			Op::Less => *v < self.version,
+			Op::LessEq => *v <= self.version,
+			Op::Tilde => *v >= self.version && v.same_minor(&self.version),
+		}
+	}
+}
+
+impl Display for VersionReq {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "{}{}", self.op.as_str(), self.version)
+	}
+}
+
+impl FromStr for VersionReq {
+	type Err = ParseVersionError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let trimmed = s.trim();
+		// Two character operators must be checked before their one character prefixes
+		let (op, rest) = [
+			(Op::GreaterEq, ">="),
+			(Op::LessEq, "<="),
+			(Op::Greater, ">"),
+			(Op::Less, "<"),
+			(Op::Exact, "="),
+			(Op::Tilde, "~"),
+		]
+			.into_iter()
+			.find_map(|(op, prefix)| trimmed.strip_prefix(prefix).map(|rest| (op, rest)))
+			.unwrap_or((Op::Exact, trimmed));
+
+		let version = Version::from_str(rest).map_err(|e| e.offset_span(span_of(s, rest).start))?;
+		Ok(Self::new(op, version))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::str::FromStr;
+
+	use crate::req::{Op, VersionReq};
+	use crate::{ParseVersionError, Version};
+
+	fn req(s: &str) -> VersionReq {
+		VersionReq::from_str(s).unwrap()
+	}
+
+	#[test]
+	fn parse_operators() {
+		let v = Version::new(2, 30, 0, 0);
+		assert_eq!(req("=2.30.0.0"), VersionReq::new(Op::Exact, v));
+		assert_eq!(req(">2.30.0.0"), VersionReq::new(Op::Greater, v));
+		assert_eq!(req(">=2.30.0.0"), VersionReq::new(Op::GreaterEq, v));
+		assert_eq!(req("<2.30.0.0"), VersionReq::new(Op::Less, v));
+		assert_eq!(req("<=2.30.0.0"), VersionReq::new(Op::LessEq, v));
+		assert_eq!(req("~2.30.0.0"), VersionReq::new(Op::Tilde, v));
+		assert_eq!(req("2.30.0.0"), VersionReq::new(Op::Exact, v));
+		assert_eq!(req(">= 2.30.0.0"), VersionReq::new(Op::GreaterEq, v));
+	}
+
+	#[test]
+	fn parse_invalid() {
+		assert_eq!(VersionReq::from_str(">="), Err(ParseVersionError::Empty));
+		assert!(VersionReq::from_str("!2.30.0.0").is_err());
+	}
+
+	#[test]
+	fn invalid_component_span() {
+		for (s, span) in [(">=2.3x.5.14", 4..6), (" ~ 2.3x.5.14", 5..7), ("2.3x.5.14", 2..4)] {
+			let Err(ParseVersionError::InvalidComponent { span: found, .. }) = VersionReq::from_str(s) else { panic!() };
+			assert_eq!(found, span, "{s}");
+		}
+	}
+
+	#[test]
+	fn display_round_trip() {
+		for s in ["=2.30.0.0", ">2.30.0.0", ">=2.30.0.0", "<2.30.0.0", "<=2.30.0.0", "~2.30.0.0"] {
+			assert_eq!(req(s).to_string(), s);
+		}
+	}
+
+	#[test]
+	fn exact() {
+		let r = req("=2.33.5.14");
+		assert!(r.matches(&Version::new(2, 33, 5, 14)));
+		assert!(!r.matches(&Version::new(2, 33, 5, 15)));
+	}
+
+	#[test]
+	fn greater() {
+		let r = req(">2.33.5.14");
+		assert!(r.matches(&Version::new(2, 33, 5, 15)));
+		assert!(!r.matches(&Version::new(2, 33, 5, 14)));
+		assert!(!r.matches(&Version::new(2, 32, 0, 0)));
+	}
+
+	#[test]
+	fn greater_eq() {
+		let r = req(">=2.30.0.0");
+		assert!(r.matches(&Version::new(2, 30, 0, 0)));
+		assert!(r.matches(&Version::new(2, 33, 5, 14)));
+		assert!(!r.matches(&Version::new(2, 29, u16::MAX, u16::MAX)));
+	}
+
+	#[test]
+	fn less() {
+		let r = req("<2.33.0.0");
+		assert!(r.matches(&Version::new(2, 32, 9, 9)));
+		assert!(!r.matches(&Version::new(2, 33, 0, 0)));
+	}
+
+	#[test]
+	fn less_eq() {
+		let r = req("<=2.33.0.0");
+		assert!(r.matches(&Version::new(2, 33, 0, 0)));
+		assert!(!r.matches(&Version::new(2, 33, 0, 1)));
+	}
+
+	#[test]
+	fn tilde() {
+		let r = req("~2.33.5.0");
+		assert!(r.matches(&Version::new(2, 33, 5, 0)));
+		assert!(r.matches(&Version::new(2, 33, 5, 14)));
+		assert!(!r.matches(&Version::new(2, 33, 4, 99)));
+		assert!(!r.matches(&Version::new(2, 33, 6, 0)));
+		assert!(!r.matches(&Version::new(2, 34, 5, 0)));
+	}
+}
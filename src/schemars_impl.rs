@@ -0,0 +1,41 @@
+use alloc::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::Version;
+
+/// Describes the human-readable serde form, the dotted string
+impl JsonSchema for Version {
+	fn schema_name() -> Cow<'static, str> {
+		"Version".into()
+	}
+
+	fn schema_id() -> Cow<'static, str> {
+		// The public path, not the private module this impl lives in
+		"wt_version::Version".into()
+	}
+
+	fn json_schema(_: &mut SchemaGenerator) -> Schema {
+		json_schema!({
+			"type": "string",
+			"pattern": r"^\d+\.\d+\.\d+\.\d+$",
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::Version;
+
+	#[test]
+	fn string_schema() {
+		let schema = schemars::schema_for!(Version);
+		assert_eq!(schema.get("type").unwrap(), "string");
+		assert_eq!(schema.get("pattern").unwrap(), r"^\d+\.\d+\.\d+\.\d+$");
+	}
+
+	#[test]
+	fn public_schema_id() {
+		assert_eq!(<Version as schemars::JsonSchema>::schema_id(), "wt_version::Version");
+	}
+}
@@ -0,0 +1,189 @@
+use alloc::string::ToString;
+use core::fmt::{self, Formatter};
+use core::str::FromStr;
+
+use serde::de::{Error, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Version;
+
+impl Serialize for Version {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		if serializer.is_human_readable() {
+			serializer.serialize_str(&self.to_string())
+		} else {
+			serializer.serialize_u64(self.to_u64())
+		}
+	}
+}
+
+impl<'de> Deserialize<'de> for Version {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		if deserializer.is_human_readable() {
+			// Also accepts the legacy `{"global":2,"major":33,"minor":5,"patch":14}` struct form and `[2,33,5,14]`
+			deserializer.deserialize_any(VersionVisitor)
+		} else {
+			deserializer.deserialize_u64(PackedVisitor)
+		}
+	}
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+	Global,
+	Major,
+	Minor,
+	Patch,
+	/// Unknown keys, ignored like the derived impl this replaces did
+	#[serde(other)]
+	Other,
+}
+
+const FIELDS: &[&str] = &["global", "major", "minor", "patch"];
+
+/// The packed [`Version::to_u64`] of binary formats
+struct PackedVisitor;
+
+impl<'de> Visitor<'de> for PackedVisitor {
+	type Value = Version;
+
+	fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+		formatter.write_str("a packed version u64")
+	}
+
+	fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+		Ok(Version::from_u64(v))
+	}
+}
+
+/// The forms of human readable formats, which never accept a bare integer
+struct VersionVisitor;
+
+impl<'de> Visitor<'de> for VersionVisitor {
+	type Value = Version;
+
+	fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+		formatter.write_str("a version string such as \"2.33.5.14\", a struct or an array of its components")
+	}
+
+	fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+		Version::from_str(v).map_err(E::custom)
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let mut components = [0; 4];
+		for (index, component) in components.iter_mut().enumerate() {
+			*component = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(index, &self))?;
+		}
+		let mut len = components.len();
+		while seq.next_element::<IgnoredAny>()?.is_some() {
+			len += 1;
+		}
+		if len != components.len() {
+			return Err(A::Error::invalid_length(len, &self));
+		}
+		Ok(Version::from(components))
+	}
+
+	fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+		let mut components = [None; 4];
+		while let Some(field) = map.next_key::<Field>()? {
+			let index = match field {
+				Field::Global => 0,
+				Field::Major => 1,
+				Field::Minor => 2,
+				Field::Patch => 3,
+				Field::Other => {
+					map.next_value::<IgnoredAny>()?;
+					continue;
+				}
+			};
+			if components[index].is_some() {
+				return Err(A::Error::duplicate_field(FIELDS[index]));
+			}
+			components[index] = Some(map.next_value()?);
+		}
+
+		let mut get = |index: usize| components[index].take().ok_or_else(|| A::Error::missing_field(FIELDS[index]));
+		Ok(Version::new(get(0)?, get(1)?, get(2)?, get(3)?))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::Version;
+
+	#[test]
+	fn serialize_as_string() {
+		assert_eq!(serde_json::to_string(&Version::new(2, 33, 5, 14)).unwrap(), "\"2.33.5.14\"")
+	}
+
+	#[test]
+	fn deserialize_from_string() {
+		assert_eq!(serde_json::from_str::<Version>("\"2.33.5.14\"").unwrap(), Version::new(2, 33, 5, 14))
+	}
+
+	#[test]
+	fn round_trip() {
+		let v = Version::new(2, 33, 5, 14);
+		assert_eq!(serde_json::from_str::<Version>(&serde_json::to_string(&v).unwrap()).unwrap(), v)
+	}
+
+	#[test]
+	fn binary_round_trip() {
+		let v = Version::new(2, 33, 5, 14);
+		let bytes = bincode::serialize(&v).unwrap();
+		assert_eq!(bytes, bincode::serialize(&v.to_u64()).unwrap());
+		assert_eq!(bincode::deserialize::<Version>(&bytes).unwrap(), v)
+	}
+
+	#[test]
+	fn deserialize_legacy_struct() {
+		let legacy = r#"{"global":2,"major":33,"minor":5,"patch":14}"#;
+		assert_eq!(serde_json::from_str::<Version>(legacy).unwrap(), Version::new(2, 33, 5, 14));
+		assert_eq!(serde_json::from_str::<Version>(legacy).unwrap(), serde_json::from_str::<Version>("\"2.33.5.14\"").unwrap());
+	}
+
+	#[test]
+	fn deserialize_legacy_struct_unknown_field() {
+		let legacy = r#"{"global":2,"major":33,"x":[1],"minor":5,"patch":14,"y":{"z":null}}"#;
+		assert_eq!(serde_json::from_str::<Version>(legacy).unwrap(), Version::new(2, 33, 5, 14));
+	}
+
+	#[test]
+	fn deserialize_legacy_struct_duplicate_field() {
+		let e = serde_json::from_str::<Version>(r#"{"global":2,"major":33,"minor":5,"patch":14,"major":34}"#).unwrap_err();
+		assert!(e.to_string().starts_with("duplicate field `major`"), "{e}");
+	}
+
+	#[test]
+	fn deserialize_legacy_struct_missing_field() {
+		assert!(serde_json::from_str::<Version>(r#"{"global":2,"major":33,"minor":5}"#).is_err())
+	}
+
+	#[test]
+	fn deserialize_array() {
+		assert_eq!(serde_json::from_str::<Version>("[2,33,5,14]").unwrap(), Version::new(2, 33, 5, 14))
+	}
+
+	#[test]
+	fn deserialize_array_wrong_length() {
+		assert!(serde_json::from_str::<Version>("[2,33,5]").is_err());
+		assert!(serde_json::from_str::<Version>("[2,33,5,14,0]").is_err());
+		assert!(serde_json::from_str::<Version>("[]").is_err());
+		let e = serde_json::from_str::<Version>("[1,2,3,4,5,6]").unwrap_err();
+		assert!(e.to_string().starts_with("invalid length 6"), "{e}");
+	}
+
+	#[test]
+	fn deserialize_rejects_integer() {
+		assert!(serde_json::from_str::<Version>("5").is_err());
+		assert!(serde_json::from_str::<Version>("562958543486990").is_err());
+	}
+
+	#[test]
+	fn deserialize_invalid_string() {
+		assert!(serde_json::from_str::<Version>("\"2.33.5\"").is_err())
+	}
+}
@@ -0,0 +1,133 @@
+use alloc::string::{String, ToString};
+use core::cmp::Ordering;
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+use crate::{ParseVersionError, Version};
+
+/// A version with an optional build tag, such as `2.33.5.14-dev`
+///
+/// A tagged build orders before the untagged release of the same version, tags of the same version order lexicographically
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TaggedVersion {
+	version: Version,
+	tag: Option<String>,
+}
+
+impl TaggedVersion {
+	pub const fn new(version: Version, tag: Option<String>) -> Self {
+		Self { version, tag }
+	}
+
+	pub const fn version(&self) -> Version {
+		self.version
+	}
+
+	pub fn tag(&self) -> Option<&str> {
+		self.tag.as_deref()
+	}
+}
+
+impl From<Version> for TaggedVersion {
+	fn from(version: Version) -> Self {
+		Self::new(version, None)
+	}
+}
+
+impl Display for TaggedVersion {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match &self.tag {
+			Some(tag) => write!(f, "{}-{tag}", self.version),
+			None => write!(f, "{}", self.version),
+		}
+	}
+}
+
+impl FromStr for TaggedVersion {
+	type Err = ParseVersionError;
+
+	/// Splits on the first `-`, everything after it is kept verbatim as the tag
+	///
+	/// An empty tag such as in `2.33.5.14-` is treated as untagged
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		// Only trimming the end keeps error spans relative to `s`
+		let s = s.trim_end();
+		match s.split_once('-') {
+			Some((version, tag)) => Ok(Self::new(Version::from_str(version)?, Some(tag.to_string()).filter(|tag| !tag.is_empty()))),
+			None => Ok(Self::new(Version::from_str(s)?, None)),
+		}
+	}
+}
+
+impl PartialOrd for TaggedVersion {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for TaggedVersion {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.version.cmp(&other.version).then_with(|| match (&self.tag, &other.tag) {
+			(Some(a), Some(b)) => a.cmp(b),
+			(Some(_), None) => Ordering::Less,
+			(None, Some(_)) => Ordering::Greater,
+			(None, None) => Ordering::Equal,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::str::FromStr;
+
+	use crate::tagged::TaggedVersion;
+	use crate::{ParseVersionError, Version};
+
+	fn tagged(s: &str) -> TaggedVersion {
+		TaggedVersion::from_str(s).unwrap()
+	}
+
+	#[test]
+	fn parse_tagged() {
+		let v = tagged("2.33.5.14-dev");
+		assert_eq!(v.version(), Version::new(2, 33, 5, 14));
+		assert_eq!(v.tag(), Some("dev"));
+		assert_eq!(tagged("2.33.5.14-dev-2").tag(), Some("dev-2"));
+	}
+
+	#[test]
+	fn parse_untagged() {
+		let v = tagged("2.33.5.14");
+		assert_eq!(v.version(), Version::new(2, 33, 5, 14));
+		assert_eq!(v.tag(), None);
+	}
+
+	#[test]
+	fn parse_empty_tag() {
+		let v = tagged("2.33.5.14-");
+		assert_eq!(v.tag(), None);
+		assert_eq!(v, tagged("2.33.5.14"));
+		assert_eq!(v.to_string(), "2.33.5.14");
+	}
+
+	#[test]
+	fn parse_invalid() {
+		assert_eq!(TaggedVersion::from_str("2.33.5-dev"), Err(ParseVersionError::TooFewComponents { found: 3 }));
+		assert_eq!(TaggedVersion::from_str("-dev"), Err(ParseVersionError::Empty));
+	}
+
+	#[test]
+	fn display_round_trip() {
+		for s in ["2.33.5.14-dev", "2.33.5.14"] {
+			assert_eq!(tagged(s).to_string(), s);
+		}
+	}
+
+	#[test]
+	fn tagged_before_release() {
+		assert!(tagged("2.33.5.14-dev") < tagged("2.33.5.14"));
+		assert!(tagged("2.33.5.14-dev") > tagged("2.33.5.13"));
+		assert!(tagged("2.33.5.14-alpha") < tagged("2.33.5.14-beta"));
+		assert_eq!(tagged("2.33.5.14"), TaggedVersion::from(Version::new(2, 33, 5, 14)));
+	}
+}
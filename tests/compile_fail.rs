@@ -0,0 +1,4 @@
+#[test]
+fn version_macro() {
+	trybuild::TestCases::new().compile_fail("tests/compile_fail/*.rs");
+}
@@ -0,0 +1,3 @@
+fn main() {
+	let _ = wt_version::version!(2.33.5.14.0);
+}
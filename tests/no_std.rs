@@ -0,0 +1,22 @@
+//! Exercises the API that remains available when built with `--no-default-features`
+//!
+//! Only the `std::error::Error` impls, `Version::parse_reader` and `ParseVersionError::Io` need `std`, the rest only needs `core` and `alloc`
+#![no_std]
+
+use core::str::FromStr;
+
+use wt_version::{ParseVersionError, Version};
+
+#[test]
+fn construct_and_compare() {
+	let v = Version::new(2, 33, 5, 14);
+	assert_eq!(v.major(), 33);
+	assert!(v < v.bump_patch());
+	assert_eq!(Version::from_u64(v.to_u64()), v);
+}
+
+#[test]
+fn parse() {
+	assert_eq!(Version::from_str("2.33.5.14"), Ok(Version::new(2, 33, 5, 14)));
+	assert_eq!(Version::from_str("2.33.5"), Err(ParseVersionError::TooFewComponents { found: 3 }));
+}